@@ -1,6 +1,6 @@
 use anyhow::Context;
 use sqlx::mysql::MySqlQueryAs;
-use sqlx::MySqlPool;
+use sqlx::{Done, MySqlPool};
 use std::env;
 use structopt::StructOpt;
 
@@ -49,7 +49,7 @@ async fn main(args: Args) -> anyhow::Result<()> {
 
 async fn add_todo(pool: &MySqlPool, description: String) -> anyhow::Result<u64> {
     // Insert the TODO, then obtain the ID of this row
-    sqlx::query!(
+    let todo_id = sqlx::query!(
         r#"
 INSERT INTO todos ( description )
 VALUES ( ? )
@@ -57,13 +57,10 @@ VALUES ( ? )
         description
     )
     .execute(pool)
-    .await?;
-
-    let rec: (u64,) = sqlx::query_as("SELECT LAST_INSERT_ID()")
-        .fetch_one(pool)
-        .await?;
+    .await?
+    .last_insert_id();
 
-    Ok(rec.0)
+    Ok(todo_id)
 }
 
 async fn complete_todo(pool: &MySqlPool, id: u64) -> anyhow::Result<bool> {
@@ -76,7 +73,8 @@ WHERE id = ?
         id
     )
     .execute(pool)
-    .await?;
+    .await?
+    .rows_affected();
 
     Ok(rows_affected > 0)
 }
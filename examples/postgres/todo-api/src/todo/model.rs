@@ -1,7 +1,7 @@
 use serde::{Serialize, Deserialize};
 use actix_web::{HttpResponse, HttpRequest, Responder, Error};
 use futures::future::{ready, Ready};
-use sqlx::{PgPool, FromRow, Row};
+use sqlx::{PgPool, FromRow, Row, Done};
 use sqlx::postgres::PgRow;
 use anyhow::Result;
 
@@ -125,6 +125,6 @@ impl Todo {
             .await?;
 
         tx.commit().await?;
-        Ok(deleted)
+        Ok(deleted.rows_affected())
     }
 }
@@ -1,5 +1,5 @@
 use anyhow::Context;
-use sqlx::PgPool;
+use sqlx::{Done, PgPool};
 use std::env;
 use structopt::StructOpt;
 
@@ -71,7 +71,8 @@ WHERE id = $1
         id
     )
     .execute(&mut pool)
-    .await?;
+    .await?
+    .rows_affected();
 
     Ok(rows_affected > 0)
 }
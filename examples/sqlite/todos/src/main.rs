@@ -1,5 +1,5 @@
 use anyhow::Context;
-use sqlx::SqlitePool;
+use sqlx::{Done, SqlitePool};
 use std::env;
 use structopt::StructOpt;
 
@@ -50,7 +50,7 @@ async fn add_todo(pool: &SqlitePool, description: String) -> anyhow::Result<i64>
     let mut conn = pool.acquire().await?;
 
     // Insert the TODO, then obtain the ID of this row
-    sqlx::query!(
+    let id = sqlx::query!(
         r#"
 INSERT INTO todos ( description )
 VALUES ( $1 )
@@ -58,13 +58,10 @@ VALUES ( $1 )
         description
     )
     .execute(&mut conn)
-    .await?;
-
-    let rec: (i64,) = sqlx::query_as("SELECT last_insert_rowid()")
-        .fetch_one(&mut conn)
-        .await?;
+    .await?
+    .last_insert_rowid();
 
-    Ok(rec.0)
+    Ok(id)
 }
 
 async fn complete_todo(pool: &SqlitePool, id: i64) -> anyhow::Result<bool> {
@@ -77,7 +74,8 @@ WHERE id = $1
         id
     )
     .execute(pool)
-    .await?;
+    .await?
+    .rows_affected();
 
     Ok(rows_affected > 0)
 }
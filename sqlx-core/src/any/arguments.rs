@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::fmt::{self, Write};
+
+use crate::any::{Any, AnyKind};
+use crate::arguments::Arguments;
+use crate::encode::Encode;
+use crate::error::Error;
+use crate::types::Type;
+
+/// Implementation of [`Arguments`] for the [`Any`] driver.
+///
+/// The values are not encoded for a database until the query is executed and the concrete
+/// database driver is known.
+///
+/// The placeholders written by a [`QueryBuilder`](crate::query_builder::QueryBuilder) depend on
+/// the database; create the arguments with [`AnyArguments::new`] and pass them to
+/// [`QueryBuilder::with_arguments`](crate::query_builder::QueryBuilder::with_arguments) to
+/// build a query for a PostgreSQL or MSSQL connection.
+#[derive(Default)]
+pub struct AnyArguments<'q> {
+    values: AnyArgumentBuffer<'q>,
+
+    // the database that placeholders are written for, if known
+    kind: Option<AnyKind>,
+
+    // set once a placeholder was written; such a query only works on a database
+    // with the same placeholder syntax
+    has_placeholders: Cell<bool>,
+}
+
+impl<'q> AnyArguments<'q> {
+    /// Creates empty arguments that write placeholders in the syntax of the database `kind`.
+    pub fn new(kind: AnyKind) -> Self {
+        AnyArguments {
+            kind: Some(kind),
+            ..Default::default()
+        }
+    }
+
+    // placeholders written for one database are a syntax error on another, so reject
+    // the query up front with an error that says why
+    pub(crate) fn check_placeholders(&self, kind: AnyKind) -> Result<(), Error> {
+        if !self.has_placeholders.get()
+            || self.kind == Some(kind)
+            || (uses_question_marks(self.kind) && uses_question_marks(Some(kind)))
+        {
+            return Ok(());
+        }
+
+        Err(Error::InvalidArgument(format!(
+            "the placeholders of this query were written for {}, but it is executed on {:?}; \
+             build it with `QueryBuilder::with_arguments(.., AnyArguments::new(kind))`",
+            self.kind.map_or_else(
+                || "an unknown database".to_owned(),
+                |kind| format!("{:?}", kind)
+            ),
+            kind
+        )))
+    }
+}
+
+// `$N` for PostgreSQL and `@pN` for MSSQL; every other database, and a database that is not
+// known yet, uses `?`
+fn uses_question_marks(kind: Option<AnyKind>) -> bool {
+    match kind {
+        #[cfg(feature = "postgres")]
+        Some(AnyKind::Postgres) => false,
+
+        #[cfg(feature = "mssql")]
+        Some(AnyKind::Mssql) => false,
+
+        _ => true,
+    }
+}
+
+impl<'q> Arguments<'q> for AnyArguments<'q> {
+    type Database = Any;
+
+    fn reserve(&mut self, additional: usize, _size: usize) {
+        self.values.0.reserve(additional);
+    }
+
+    fn add<T>(&mut self, value: T)
+    where
+        T: 'q + Encode<'q, Self::Database> + Type<Self::Database>,
+    {
+        let _ = value.encode(&mut self.values);
+    }
+
+    fn new_like(&self) -> Self {
+        AnyArguments {
+            kind: self.kind,
+            ..Default::default()
+        }
+    }
+
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        self.has_placeholders.set(true);
+
+        match self.kind {
+            #[cfg(feature = "postgres")]
+            Some(AnyKind::Postgres) => write!(writer, "${}", self.values.0.len()),
+
+            #[cfg(feature = "mssql")]
+            Some(AnyKind::Mssql) => write!(writer, "@p{}", self.values.0.len()),
+
+            _ => writer.write_str("?"),
+        }
+    }
+}
+
+/// The argument buffer of the [`Any`] driver.
+///
+/// Values are kept in a form that every database driver can bind and are converted to the
+/// arguments of the concrete driver when the query is executed.
+#[derive(Default)]
+pub struct AnyArgumentBuffer<'q>(pub(crate) Vec<AnyArgumentValue<'q>>);
+
+pub(crate) enum AnyArgumentValue<'q> {
+    Bool(Option<bool>),
+    I32(Option<i32>),
+    I64(Option<i64>),
+    F32(Option<f32>),
+    F64(Option<f64>),
+    Str(Option<Cow<'q, str>>),
+}
+
+macro_rules! impl_from_any_arguments {
+    ($Arguments:ty) => {
+        impl<'q> From<AnyArguments<'q>> for $Arguments {
+            fn from(any: AnyArguments<'q>) -> Self {
+                let mut args = <$Arguments>::default();
+                args.reserve(any.values.0.len(), 0);
+
+                for value in any.values.0 {
+                    match value {
+                        AnyArgumentValue::Bool(value) => args.add(value),
+                        AnyArgumentValue::I32(value) => args.add(value),
+                        AnyArgumentValue::I64(value) => args.add(value),
+                        AnyArgumentValue::F32(value) => args.add(value),
+                        AnyArgumentValue::F64(value) => args.add(value),
+
+                        AnyArgumentValue::Str(Some(Cow::Borrowed(value))) => args.add(value),
+                        AnyArgumentValue::Str(value) => args.add(value.map(Cow::into_owned)),
+                    }
+                }
+
+                args
+            }
+        }
+    };
+}
+
+#[cfg(feature = "postgres")]
+impl_from_any_arguments!(crate::postgres::PgArguments);
+
+#[cfg(feature = "mysql")]
+impl_from_any_arguments!(crate::mysql::MySqlArguments);
+
+#[cfg(feature = "sqlite")]
+impl_from_any_arguments!(crate::sqlite::SqliteArguments<'q>);
+
+#[cfg(feature = "mssql")]
+impl_from_any_arguments!(crate::mssql::MssqlArguments);
@@ -0,0 +1,95 @@
+use crate::any::{Any, AnyTypeInfo};
+use crate::column::Column;
+use crate::describe;
+use crate::ext::ustr::UStr;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PgColumn;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlColumn;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteColumn;
+
+#[cfg(feature = "mssql")]
+use crate::mssql::MssqlColumn;
+
+/// Implementation of [`Column`] for the [`Any`] driver; a copy of the column
+/// information of the database driver in use.
+#[derive(Debug, Clone)]
+pub struct AnyColumn {
+    pub(crate) ordinal: usize,
+    pub(crate) name: UStr,
+    pub(crate) type_info: Option<AnyTypeInfo>,
+}
+
+impl crate::column::private_column::Sealed for AnyColumn {}
+
+impl Column for AnyColumn {
+    type Database = Any;
+
+    fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn type_info(&self) -> Option<&AnyTypeInfo> {
+        self.type_info.as_ref()
+    }
+
+    fn from_describe(ordinal: usize, column: describe::Column<Any>) -> Self {
+        AnyColumn {
+            ordinal,
+            name: column.name.into(),
+            type_info: column.type_info,
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<&'_ PgColumn> for AnyColumn {
+    fn from(column: &PgColumn) -> Self {
+        AnyColumn {
+            ordinal: column.ordinal,
+            name: column.name.clone(),
+            type_info: Some(column.type_info.clone().into()),
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<&'_ MySqlColumn> for AnyColumn {
+    fn from(column: &MySqlColumn) -> Self {
+        AnyColumn {
+            ordinal: column.ordinal,
+            name: column.name.clone().unwrap_or(UStr::Static("")),
+            type_info: column.type_info.clone().map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<&'_ SqliteColumn> for AnyColumn {
+    fn from(column: &SqliteColumn) -> Self {
+        AnyColumn {
+            ordinal: column.ordinal,
+            name: column.name.clone(),
+            type_info: column.type_info.clone().map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl From<&'_ MssqlColumn> for AnyColumn {
+    fn from(column: &MssqlColumn) -> Self {
+        AnyColumn {
+            ordinal: column.ordinal,
+            name: column.name.clone(),
+            type_info: Some(column.type_info.clone().into()),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use either::Either;
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::{future, stream, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
+
+use crate::any::connection::AnyConnectionKind;
+use crate::any::{Any, AnyArguments, AnyConnection, AnyDone, AnyKind, AnyRow, AnyTypeInfo};
+use crate::database::{Database, HasArguments};
+use crate::describe::{Column, Describe};
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::query::Query;
+
+impl<'c> Executor<'c> for &'c mut AnyConnection {
+    type Database = Any;
+
+    fn fetch_many<'e, 'q: 'e, E: 'q>(
+        self,
+        mut query: E,
+    ) -> BoxStream<'e, Result<Either<AnyDone, AnyRow>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let arguments = query.take_arguments();
+        let persistent = query.persistent();
+        let timeout = query.timeout();
+        let query = query.query();
+
+        if let Err(error) = check_arguments(self.kind(), arguments.as_ref()) {
+            return stream::once(future::err(error)).boxed();
+        }
+
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn
+                .fetch_many(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map_left(Into::into).map_right(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn
+                .fetch_many(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map_left(Into::into).map_right(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn
+                .fetch_many(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map_left(Into::into).map_right(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn
+                .fetch_many(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map_left(Into::into).map_right(Into::into))
+                .boxed(),
+        }
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E: 'q>(
+        self,
+        mut query: E,
+    ) -> BoxFuture<'e, Result<Option<AnyRow>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let arguments = query.take_arguments();
+        let persistent = query.persistent();
+        let timeout = query.timeout();
+        let query = query.query();
+
+        if let Err(error) = check_arguments(self.kind(), arguments.as_ref()) {
+            return future::err(error).boxed();
+        }
+
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn
+                .fetch_optional(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn
+                .fetch_optional(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn
+                .fetch_optional(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn
+                .fetch_optional(forward(
+                    query,
+                    arguments.map(Into::into),
+                    persistent,
+                    timeout,
+                ))
+                .map_ok(|v| v.map(Into::into))
+                .boxed(),
+        }
+    }
+
+    #[doc(hidden)]
+    fn describe<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Describe<Self::Database>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let query = query.query();
+
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.describe(query).map_ok(map_describe).boxed(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.describe(query).map_ok(map_describe).boxed(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.describe(query).map_ok(map_describe).boxed(),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn.describe(query).map_ok(map_describe).boxed(),
+        }
+    }
+}
+
+fn check_arguments(kind: AnyKind, arguments: Option<&AnyArguments<'_>>) -> Result<(), Error> {
+    arguments.map_or(Ok(()), |arguments| arguments.check_placeholders(kind))
+}
+
+// re-assemble the query for the underlying driver, carrying over its statement cache setting
+// and its timeout
+fn forward<'q, DB: Database>(
+    query: &'q str,
+    arguments: Option<<DB as HasArguments<'q>>::Arguments>,
+    persistent: bool,
+    timeout: Option<Duration>,
+) -> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
+    Query {
+        query,
+        arguments,
+        database: PhantomData,
+        persistent,
+        timeout,
+    }
+}
+
+fn map_describe<DB: Database>(describe: Describe<DB>) -> Describe<Any>
+where
+    AnyTypeInfo: From<DB::TypeInfo>,
+{
+    Describe {
+        params: describe
+            .params
+            .into_iter()
+            .map(|ty| ty.map(Into::into))
+            .collect(),
+
+        columns: describe
+            .columns
+            .into_iter()
+            .map(|column| Column {
+                name: column.name,
+                type_info: column.type_info.map(Into::into),
+                not_null: column.not_null,
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,245 @@
+use futures_core::future::BoxFuture;
+
+use crate::any::options::AnyConnectOptionsKind;
+use crate::any::{Any, AnyConnectOptions, AnyKind};
+use crate::connection::{Connect, Connection, LogSettings};
+use crate::error::Error;
+
+#[cfg(feature = "postgres")]
+use crate::postgres;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite;
+
+#[cfg(feature = "mssql")]
+use crate::mssql;
+
+#[cfg(feature = "mysql")]
+use crate::mysql;
+
+mod executor;
+
+/// A connection to _any_ SQLx database.
+///
+/// The database driver used is determined by the scheme
+/// of the connection url.
+///
+/// ```text
+/// postgres://postgres@localhost/test
+/// sqlite://a.sqlite
+/// ```
+#[derive(Debug)]
+pub struct AnyConnection(pub(super) AnyConnectionKind);
+
+#[derive(Debug)]
+pub(crate) enum AnyConnectionKind {
+    #[cfg(feature = "postgres")]
+    Postgres(postgres::PgConnection),
+
+    #[cfg(feature = "mssql")]
+    Mssql(mssql::MssqlConnection),
+
+    #[cfg(feature = "mysql")]
+    MySql(mysql::MySqlConnection),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(sqlite::SqliteConnection),
+}
+
+impl AnyConnection {
+    /// Returns the kind of database driver that this connection is using.
+    pub fn kind(&self) -> AnyKind {
+        match self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(_) => AnyKind::Postgres,
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(_) => AnyKind::MySql,
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(_) => AnyKind::Sqlite,
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_) => AnyKind::Mssql,
+        }
+    }
+}
+
+macro_rules! delegate_to {
+    ($self:ident.$method:ident($($arg:ident),*)) => {
+        match &$self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.$method($($arg),*),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.$method($($arg),*),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.$method($($arg),*),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn.$method($($arg),*),
+        }
+    };
+}
+
+macro_rules! delegate_to_mut {
+    ($self:ident.$method:ident($($arg:ident),*)) => {
+        match &mut $self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.$method($($arg),*),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.$method($($arg),*),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.$method($($arg),*),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn.$method($($arg),*),
+        }
+    };
+}
+
+impl Connection for AnyConnection {
+    type Database = Any;
+
+    fn close(self) -> BoxFuture<'static, Result<(), Error>> {
+        match self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.close(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.close(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.close(),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn.close(),
+        }
+    }
+
+    fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        delegate_to_mut!(self.ping())
+    }
+
+    fn set_logging(&mut self, settings: LogSettings) {
+        delegate_to_mut!(self.set_logging(settings))
+    }
+
+    fn cached_statements_size(&self) -> usize {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.cached_statements_size(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.cached_statements_size(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.cached_statements_size(),
+
+            // no cache
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_) => 0,
+        }
+    }
+
+    fn clear_cached_statements(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.clear_cached_statements(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.clear_cached_statements(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.clear_cached_statements(),
+
+            // no cache
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_) => Box::pin(futures_util::future::ok(())),
+        }
+    }
+
+    #[doc(hidden)]
+    fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        delegate_to_mut!(self.flush())
+    }
+
+    #[doc(hidden)]
+    fn should_flush(&self) -> bool {
+        delegate_to!(self.should_flush())
+    }
+
+    #[doc(hidden)]
+    fn get_ref(&self) -> &Self {
+        self
+    }
+
+    #[doc(hidden)]
+    fn get_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Connect for AnyConnection {
+    type Options = AnyConnectOptions;
+
+    #[inline]
+    fn connect_with(options: &Self::Options) -> BoxFuture<'_, Result<Self, Error>> {
+        Box::pin(async move {
+            Ok(AnyConnection(match &options.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectOptionsKind::Postgres(options) => {
+                    postgres::PgConnection::connect_with(options).await?.into()
+                }
+
+                #[cfg(feature = "mysql")]
+                AnyConnectOptionsKind::MySql(options) => {
+                    mysql::MySqlConnection::connect_with(options).await?.into()
+                }
+
+                #[cfg(feature = "sqlite")]
+                AnyConnectOptionsKind::Sqlite(options) => {
+                    sqlite::SqliteConnection::connect_with(options)
+                        .await?
+                        .into()
+                }
+
+                #[cfg(feature = "mssql")]
+                AnyConnectOptionsKind::Mssql(options) => {
+                    mssql::MssqlConnection::connect_with(options).await?.into()
+                }
+            }))
+        })
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<postgres::PgConnection> for AnyConnectionKind {
+    fn from(conn: postgres::PgConnection) -> Self {
+        AnyConnectionKind::Postgres(conn)
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl From<mssql::MssqlConnection> for AnyConnectionKind {
+    fn from(conn: mssql::MssqlConnection) -> Self {
+        AnyConnectionKind::Mssql(conn)
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<mysql::MySqlConnection> for AnyConnectionKind {
+    fn from(conn: mysql::MySqlConnection) -> Self {
+        AnyConnectionKind::MySql(conn)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<sqlite::SqliteConnection> for AnyConnectionKind {
+    fn from(conn: sqlite::SqliteConnection) -> Self {
+        AnyConnectionKind::Sqlite(conn)
+    }
+}
@@ -0,0 +1,95 @@
+use std::iter::{Extend, IntoIterator};
+
+use crate::any::Any;
+use crate::done::Done;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PgDone;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlDone;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteDone;
+
+#[cfg(feature = "mssql")]
+use crate::mssql::MssqlDone;
+
+/// Implementation of [`Done`] for the [`Any`] driver.
+#[derive(Debug, Default)]
+pub struct AnyDone {
+    pub(crate) rows_affected: u64,
+    pub(crate) last_insert_id: Option<i64>,
+}
+
+impl AnyDone {
+    /// Returns the ID of the last inserted row, on the databases that report it with the
+    /// result of a query: the `AUTO_INCREMENT` value on MySQL and the `ROWID` on SQLite.
+    ///
+    /// Always `None` on PostgreSQL and MSSQL, use `RETURNING` or `OUTPUT` there instead.
+    pub fn last_insert_id(&self) -> Option<i64> {
+        self.last_insert_id
+    }
+}
+
+impl Done for AnyDone {
+    type Database = Any;
+
+    fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+}
+
+impl Extend<AnyDone> for AnyDone {
+    fn extend<T: IntoIterator<Item = AnyDone>>(&mut self, iter: T) {
+        for elem in iter {
+            self.rows_affected += elem.rows_affected;
+
+            if elem.last_insert_id.is_some() {
+                self.last_insert_id = elem.last_insert_id;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<PgDone> for AnyDone {
+    fn from(done: PgDone) -> Self {
+        AnyDone {
+            rows_affected: done.rows_affected,
+            last_insert_id: None,
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<MySqlDone> for AnyDone {
+    fn from(done: MySqlDone) -> Self {
+        AnyDone {
+            rows_affected: done.rows_affected,
+            // the ID of the last inserted row is reported as `0` when no row was inserted
+            last_insert_id: Some(done.last_insert_id as i64).filter(|id| *id != 0),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<SqliteDone> for AnyDone {
+    fn from(done: SqliteDone) -> Self {
+        AnyDone {
+            rows_affected: done.changes,
+            // the ROWID is reported as `0` when no row was inserted
+            last_insert_id: Some(done.last_insert_rowid).filter(|id| *id != 0),
+        }
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl From<MssqlDone> for AnyDone {
+    fn from(done: MssqlDone) -> Self {
+        AnyDone {
+            rows_affected: done.rows_affected,
+            last_insert_id: None,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// The database driver that an [`AnyConnection`](super::AnyConnection) is using.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnyKind {
+    #[cfg(feature = "postgres")]
+    Postgres,
+
+    #[cfg(feature = "mysql")]
+    MySql,
+
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+
+    #[cfg(feature = "mssql")]
+    Mssql,
+}
+
+impl FromStr for AnyKind {
+    type Err = Error;
+
+    fn from_str(url: &str) -> Result<Self, Self::Err> {
+        match url {
+            #[cfg(feature = "postgres")]
+            _ if url.starts_with("postgres:") || url.starts_with("postgresql:") => {
+                Ok(AnyKind::Postgres)
+            }
+
+            #[cfg(not(feature = "postgres"))]
+            _ if url.starts_with("postgres:") || url.starts_with("postgresql:") => {
+                Err(Error::ParseConnectOptions("database URL has the scheme of a PostgreSQL database but the `postgres` feature is not enabled".into()))
+            }
+
+            #[cfg(feature = "mysql")]
+            _ if url.starts_with("mysql:") || url.starts_with("mariadb:") => Ok(AnyKind::MySql),
+
+            #[cfg(not(feature = "mysql"))]
+            _ if url.starts_with("mysql:") || url.starts_with("mariadb:") => {
+                Err(Error::ParseConnectOptions("database URL has the scheme of a MySQL database but the `mysql` feature is not enabled".into()))
+            }
+
+            #[cfg(feature = "sqlite")]
+            _ if url.starts_with("sqlite:") => Ok(AnyKind::Sqlite),
+
+            #[cfg(not(feature = "sqlite"))]
+            _ if url.starts_with("sqlite:") => {
+                Err(Error::ParseConnectOptions("database URL has the scheme of a SQLite database but the `sqlite` feature is not enabled".into()))
+            }
+
+            #[cfg(feature = "mssql")]
+            _ if url.starts_with("mssql:") || url.starts_with("sqlserver:") => Ok(AnyKind::Mssql),
+
+            #[cfg(not(feature = "mssql"))]
+            _ if url.starts_with("mssql:") || url.starts_with("sqlserver:") => {
+                Err(Error::ParseConnectOptions("database URL has the scheme of a MSSQL database but the `mssql` feature is not enabled".into()))
+            }
+
+            _ => Err(Error::ParseConnectOptions(format!("unrecognized database url: {:?}", url).into()))
+        }
+    }
+}
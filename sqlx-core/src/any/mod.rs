@@ -0,0 +1,37 @@
+//! Generic database driver with the specific driver selected at runtime.
+//!
+//! The driver is chosen from the scheme of the connection url (e.g., `postgres://`, `mysql://`,
+//! `sqlite://`, or `mssql://`); only the drivers whose features are enabled are available.
+
+mod arguments;
+mod column;
+mod connection;
+mod database;
+mod done;
+mod kind;
+mod options;
+mod row;
+mod transaction;
+mod type_info;
+pub mod types;
+mod value;
+
+pub use arguments::{AnyArgumentBuffer, AnyArguments};
+pub use column::AnyColumn;
+pub use connection::AnyConnection;
+pub use database::Any;
+pub use done::AnyDone;
+pub use kind::AnyKind;
+pub use options::AnyConnectOptions;
+pub use row::AnyRow;
+pub use transaction::AnyTransactionManager;
+pub use type_info::AnyTypeInfo;
+pub use value::{AnyValue, AnyValueRef};
+
+/// An alias for [`Pool`][crate::pool::Pool], specialized for **any** supported database.
+pub type AnyPool = crate::pool::Pool<Any>;
+
+// NOTE: required due to the lack of lazy normalization
+impl_into_arguments_for_arguments!(AnyArguments<'q>);
+impl_executor_for_pool_connection!(Any, AnyConnection, AnyRow);
+impl_executor_for_transaction!(Any, AnyRow);
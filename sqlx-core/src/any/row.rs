@@ -0,0 +1,140 @@
+use crate::any::{Any, AnyColumn, AnyValueRef};
+use crate::error::Error;
+use crate::row::{ColumnIndex, Row};
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PgRow;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlRow;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteRow;
+
+#[cfg(feature = "mssql")]
+use crate::mssql::MssqlRow;
+
+/// Implementation of [`Row`] for the [`Any`] driver; wraps a row of the database driver in use.
+pub struct AnyRow {
+    pub(crate) kind: AnyRowKind,
+    pub(crate) columns: Vec<AnyColumn>,
+}
+
+impl crate::row::private_row::Sealed for AnyRow {}
+
+pub(crate) enum AnyRowKind {
+    #[cfg(feature = "postgres")]
+    Postgres(PgRow),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlRow),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteRow),
+
+    #[cfg(feature = "mssql")]
+    Mssql(MssqlRow),
+}
+
+impl Row for AnyRow {
+    type Database = Any;
+
+    fn len(&self) -> usize {
+        match &self.kind {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => row.len(),
+
+            #[cfg(feature = "mysql")]
+            AnyRowKind::MySql(row) => row.len(),
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => row.len(),
+
+            #[cfg(feature = "mssql")]
+            AnyRowKind::Mssql(row) => row.len(),
+        }
+    }
+
+    fn columns(&self) -> &[AnyColumn] {
+        &self.columns
+    }
+
+    fn try_get_raw<I>(&self, index: I) -> Result<AnyValueRef<'_>, Error>
+    where
+        I: ColumnIndex<Self>,
+    {
+        let index = index.index(self)?;
+
+        match &self.kind {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => row.try_get_raw(index).map(Into::into),
+
+            #[cfg(feature = "mysql")]
+            AnyRowKind::MySql(row) => row.try_get_raw(index).map(Into::into),
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => row.try_get_raw(index).map(Into::into),
+
+            #[cfg(feature = "mssql")]
+            AnyRowKind::Mssql(row) => row.try_get_raw(index).map(Into::into),
+        }
+    }
+}
+
+impl ColumnIndex<AnyRow> for &'_ str {
+    fn index(&self, row: &AnyRow) -> Result<usize, Error> {
+        match &row.kind {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => self.index(row),
+
+            #[cfg(feature = "mysql")]
+            AnyRowKind::MySql(row) => self.index(row),
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => self.index(row),
+
+            #[cfg(feature = "mssql")]
+            AnyRowKind::Mssql(row) => self.index(row),
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<PgRow> for AnyRow {
+    fn from(row: PgRow) -> Self {
+        AnyRow {
+            columns: row.columns().iter().map(Into::into).collect(),
+            kind: AnyRowKind::Postgres(row),
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<MySqlRow> for AnyRow {
+    fn from(row: MySqlRow) -> Self {
+        AnyRow {
+            columns: row.columns().iter().map(Into::into).collect(),
+            kind: AnyRowKind::MySql(row),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<SqliteRow> for AnyRow {
+    fn from(row: SqliteRow) -> Self {
+        AnyRow {
+            columns: row.columns().iter().map(Into::into).collect(),
+            kind: AnyRowKind::Sqlite(row),
+        }
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl From<MssqlRow> for AnyRow {
+    fn from(row: MssqlRow) -> Self {
+        AnyRow {
+            columns: row.columns().iter().map(Into::into).collect(),
+            kind: AnyRowKind::Mssql(row),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use futures_util::future::BoxFuture;
+
+use crate::any::connection::AnyConnectionKind;
+use crate::any::{Any, AnyConnection};
+use crate::database::Database;
+use crate::error::{DatabaseError, Error};
+use crate::transaction::{TransactionManager, TransactionOptions};
+
+/// Implementation of [`TransactionManager`] for the [`Any`] driver; delegates to the
+/// transaction manager of the database driver in use.
+pub struct AnyTransactionManager;
+
+impl TransactionManager for AnyTransactionManager {
+    type Database = Any;
+
+    fn begin(
+        conn: &mut AnyConnection,
+        depth: usize,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::begin(
+                    conn, depth, options,
+                )
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::begin(conn, depth, options)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::begin(conn, depth, options)
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::begin(conn, depth, options)
+            }
+        }
+    }
+
+    fn commit(conn: &mut AnyConnection, depth: usize) -> BoxFuture<'_, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::commit(conn, depth)
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::commit(conn, depth)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::commit(conn, depth)
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::commit(conn, depth)
+            }
+        }
+    }
+
+    fn rollback(conn: &mut AnyConnection, depth: usize) -> BoxFuture<'_, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::rollback(conn, depth)
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::rollback(conn, depth)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::rollback(conn, depth)
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::rollback(conn, depth)
+            }
+        }
+    }
+
+    fn start_rollback(conn: &mut AnyConnection, depth: usize) {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::start_rollback(
+                    conn, depth,
+                )
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::start_rollback(conn, depth)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::start_rollback(conn, depth)
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::start_rollback(conn, depth)
+            }
+        }
+    }
+
+    fn is_retryable(error: &dyn DatabaseError) -> bool {
+        // the error is from the database driver in use, which knows its own codes
+
+        #[cfg(feature = "postgres")]
+        {
+            if error
+                .try_downcast_ref::<crate::postgres::PgDatabaseError>()
+                .is_some()
+            {
+                return <crate::postgres::Postgres as Database>::TransactionManager::is_retryable(
+                    error,
+                );
+            }
+        }
+
+        #[cfg(feature = "mysql")]
+        {
+            if error
+                .try_downcast_ref::<crate::mysql::MySqlDatabaseError>()
+                .is_some()
+            {
+                return <crate::mysql::MySql as Database>::TransactionManager::is_retryable(error);
+            }
+        }
+
+        #[cfg(feature = "sqlite")]
+        {
+            if error
+                .try_downcast_ref::<crate::sqlite::SqliteError>()
+                .is_some()
+            {
+                return <crate::sqlite::Sqlite as Database>::TransactionManager::is_retryable(
+                    error,
+                );
+            }
+        }
+
+        #[cfg(feature = "mssql")]
+        {
+            if error
+                .try_downcast_ref::<crate::mssql::MssqlDatabaseError>()
+                .is_some()
+            {
+                return <crate::mssql::Mssql as Database>::TransactionManager::is_retryable(error);
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::type_info::TypeInfo;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PgTypeInfo;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlTypeInfo;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteTypeInfo;
+
+#[cfg(feature = "mssql")]
+use crate::mssql::MssqlTypeInfo;
+
+/// Type information for a SQL type of the database driver that is in use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnyTypeInfo(pub(crate) AnyTypeInfoKind);
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AnyTypeInfoKind {
+    #[cfg(feature = "postgres")]
+    Postgres(PgTypeInfo),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlTypeInfo),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteTypeInfo),
+
+    #[cfg(feature = "mssql")]
+    Mssql(MssqlTypeInfo),
+}
+
+impl TypeInfo for AnyTypeInfo {
+    fn name(&self) -> &str {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyTypeInfoKind::Postgres(ty) => ty.name(),
+
+            #[cfg(feature = "mysql")]
+            AnyTypeInfoKind::MySql(ty) => ty.name(),
+
+            #[cfg(feature = "sqlite")]
+            AnyTypeInfoKind::Sqlite(ty) => ty.name(),
+
+            #[cfg(feature = "mssql")]
+            AnyTypeInfoKind::Mssql(ty) => ty.name(),
+        }
+    }
+}
+
+impl Display for AnyTypeInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyTypeInfoKind::Postgres(ty) => ty.fmt(f),
+
+            #[cfg(feature = "mysql")]
+            AnyTypeInfoKind::MySql(ty) => ty.fmt(f),
+
+            #[cfg(feature = "sqlite")]
+            AnyTypeInfoKind::Sqlite(ty) => ty.fmt(f),
+
+            #[cfg(feature = "mssql")]
+            AnyTypeInfoKind::Mssql(ty) => ty.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<PgTypeInfo> for AnyTypeInfo {
+    fn from(ty: PgTypeInfo) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::Postgres(ty))
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<MySqlTypeInfo> for AnyTypeInfo {
+    fn from(ty: MySqlTypeInfo) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::MySql(ty))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<SqliteTypeInfo> for AnyTypeInfo {
+    fn from(ty: SqliteTypeInfo) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::Sqlite(ty))
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl From<MssqlTypeInfo> for AnyTypeInfo {
+    fn from(ty: MssqlTypeInfo) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::Mssql(ty))
+    }
+}
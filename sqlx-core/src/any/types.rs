@@ -0,0 +1,161 @@
+//! Conversions between Rust and SQL types for the [`Any`](super::Any) driver.
+//!
+//! Only the types that are supported by _every_ database driver are available.
+//!
+//! | Rust type                             | Encode | Decode |
+//! |---------------------------------------|--------|--------|
+//! | `bool`                                | ✓      | ✓      |
+//! | `i32`                                 | ✓      | ✓      |
+//! | `i64`                                 | ✓      | ✓      |
+//! | `f32`                                 | ✓      | ✓      |
+//! | `f64`                                 | ✓      | ✓      |
+//! | `&str`                                | ✓      |        |
+//! | `String`                              | ✓      | ✓      |
+//!
+//! # Nullable
+//!
+//! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
+//! a potentially `NULL` value from the database.
+//!
+
+use std::borrow::Cow;
+
+use crate::any::arguments::AnyArgumentValue;
+use crate::any::type_info::AnyTypeInfoKind;
+use crate::any::value::AnyValueRefKind;
+use crate::any::{Any, AnyArgumentBuffer, AnyTypeInfo, AnyValueRef};
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::Postgres;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySql;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::Sqlite;
+
+#[cfg(feature = "mssql")]
+use crate::mssql::Mssql;
+
+macro_rules! impl_any_type {
+    ($ty:ty) => {
+        impl Type<Any> for $ty {
+            // the canonical type is that of the first enabled database driver; it is only
+            // consulted to describe the type in error messages
+            #[allow(unreachable_code)]
+            fn type_info() -> AnyTypeInfo {
+                #[cfg(feature = "postgres")]
+                return <$ty as Type<Postgres>>::type_info().into();
+
+                #[cfg(feature = "mysql")]
+                return <$ty as Type<MySql>>::type_info().into();
+
+                #[cfg(feature = "sqlite")]
+                return <$ty as Type<Sqlite>>::type_info().into();
+
+                #[cfg(feature = "mssql")]
+                return <$ty as Type<Mssql>>::type_info().into();
+            }
+
+            fn compatible(ty: &AnyTypeInfo) -> bool {
+                match &ty.0 {
+                    #[cfg(feature = "postgres")]
+                    AnyTypeInfoKind::Postgres(ty) => <$ty as Type<Postgres>>::compatible(ty),
+
+                    #[cfg(feature = "mysql")]
+                    AnyTypeInfoKind::MySql(ty) => <$ty as Type<MySql>>::compatible(ty),
+
+                    #[cfg(feature = "sqlite")]
+                    AnyTypeInfoKind::Sqlite(ty) => <$ty as Type<Sqlite>>::compatible(ty),
+
+                    #[cfg(feature = "mssql")]
+                    AnyTypeInfoKind::Mssql(ty) => <$ty as Type<Mssql>>::compatible(ty),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_any_encode {
+    ($ty:ty, $Variant:ident) => {
+        impl_any_encode!($ty, $Variant, |value| *value);
+    };
+
+    ($ty:ty, $Variant:ident, |$value:ident| $into:expr) => {
+        impl<'q> Encode<'q, Any> for $ty {
+            fn encode_by_ref(&self, buf: &mut AnyArgumentBuffer<'q>) -> IsNull {
+                let $value = self;
+                buf.0.push(AnyArgumentValue::$Variant(Some($into)));
+
+                // unused
+                IsNull::No
+            }
+        }
+
+        impl<'q> Encode<'q, Any> for Option<$ty> {
+            fn encode_by_ref(&self, buf: &mut AnyArgumentBuffer<'q>) -> IsNull {
+                buf.0.push(AnyArgumentValue::$Variant(
+                    self.as_ref().map(|$value| $into),
+                ));
+
+                // unused
+                IsNull::No
+            }
+        }
+    };
+}
+
+macro_rules! impl_any_decode {
+    ($ty:ty) => {
+        impl<'r> Decode<'r, Any> for $ty {
+            fn decode(value: AnyValueRef<'r>) -> Result<Self, BoxDynError> {
+                match value.0 {
+                    #[cfg(feature = "postgres")]
+                    AnyValueRefKind::Postgres(value) => {
+                        <$ty as Decode<'r, Postgres>>::decode(value)
+                    }
+
+                    #[cfg(feature = "mysql")]
+                    AnyValueRefKind::MySql(value) => <$ty as Decode<'r, MySql>>::decode(value),
+
+                    #[cfg(feature = "sqlite")]
+                    AnyValueRefKind::Sqlite(value) => <$ty as Decode<'r, Sqlite>>::decode(value),
+
+                    #[cfg(feature = "mssql")]
+                    AnyValueRefKind::Mssql(value) => <$ty as Decode<'r, Mssql>>::decode(value),
+                }
+            }
+        }
+    };
+}
+
+impl_any_type!(bool);
+impl_any_encode!(bool, Bool);
+impl_any_decode!(bool);
+
+impl_any_type!(i32);
+impl_any_encode!(i32, I32);
+impl_any_decode!(i32);
+
+impl_any_type!(i64);
+impl_any_encode!(i64, I64);
+impl_any_decode!(i64);
+
+impl_any_type!(f32);
+impl_any_encode!(f32, F32);
+impl_any_decode!(f32);
+
+impl_any_type!(f64);
+impl_any_encode!(f64, F64);
+impl_any_decode!(f64);
+
+impl_any_type!(str);
+impl_any_encode!(&'q str, Str, |value| Cow::Borrowed(*value));
+
+impl_any_type!(String);
+impl_any_encode!(String, Str, |value| Cow::Owned(value.clone()));
+impl_any_decode!(String);
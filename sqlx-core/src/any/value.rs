@@ -0,0 +1,198 @@
+use std::borrow::Cow;
+
+use crate::any::{Any, AnyTypeInfo};
+use crate::value::{Value, ValueRef};
+
+#[cfg(feature = "postgres")]
+use crate::postgres::{PgValue, PgValueRef};
+
+#[cfg(feature = "mysql")]
+use crate::mysql::{MySqlValue, MySqlValueRef};
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::{SqliteValue, SqliteValueRef};
+
+#[cfg(feature = "mssql")]
+use crate::mssql::{MssqlValue, MssqlValueRef};
+
+/// A reference to a value of the database driver in use.
+pub struct AnyValueRef<'r>(pub(crate) AnyValueRefKind<'r>);
+
+pub(crate) enum AnyValueRefKind<'r> {
+    #[cfg(feature = "postgres")]
+    Postgres(PgValueRef<'r>),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlValueRef<'r>),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteValueRef<'r>),
+
+    #[cfg(feature = "mssql")]
+    Mssql(MssqlValueRef<'r>),
+}
+
+/// An owned value of the database driver in use.
+pub struct AnyValue(pub(crate) AnyValueKind);
+
+pub(crate) enum AnyValueKind {
+    #[cfg(feature = "postgres")]
+    Postgres(PgValue),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlValue),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteValue),
+
+    #[cfg(feature = "mssql")]
+    Mssql(MssqlValue),
+}
+
+impl Value for AnyValue {
+    type Database = Any;
+
+    fn as_ref(&self) -> AnyValueRef<'_> {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyValueKind::Postgres(value) => value.as_ref().into(),
+
+            #[cfg(feature = "mysql")]
+            AnyValueKind::MySql(value) => value.as_ref().into(),
+
+            #[cfg(feature = "sqlite")]
+            AnyValueKind::Sqlite(value) => value.as_ref().into(),
+
+            #[cfg(feature = "mssql")]
+            AnyValueKind::Mssql(value) => value.as_ref().into(),
+        }
+    }
+
+    fn type_info(&self) -> Option<Cow<'_, AnyTypeInfo>> {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyValueKind::Postgres(value) => value.type_info().map(map_type_info),
+
+            #[cfg(feature = "mysql")]
+            AnyValueKind::MySql(value) => value.type_info().map(map_type_info),
+
+            #[cfg(feature = "sqlite")]
+            AnyValueKind::Sqlite(value) => value.type_info().map(map_type_info),
+
+            #[cfg(feature = "mssql")]
+            AnyValueKind::Mssql(value) => value.type_info().map(map_type_info),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyValueKind::Postgres(value) => value.is_null(),
+
+            #[cfg(feature = "mysql")]
+            AnyValueKind::MySql(value) => value.is_null(),
+
+            #[cfg(feature = "sqlite")]
+            AnyValueKind::Sqlite(value) => value.is_null(),
+
+            #[cfg(feature = "mssql")]
+            AnyValueKind::Mssql(value) => value.is_null(),
+        }
+    }
+}
+
+impl<'r> ValueRef<'r> for AnyValueRef<'r> {
+    type Database = Any;
+
+    fn to_owned(&self) -> AnyValue {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyValueRefKind::Postgres(value) => {
+                AnyValue(AnyValueKind::Postgres(ValueRef::to_owned(value)))
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyValueRefKind::MySql(value) => {
+                AnyValue(AnyValueKind::MySql(ValueRef::to_owned(value)))
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyValueRefKind::Sqlite(value) => {
+                AnyValue(AnyValueKind::Sqlite(ValueRef::to_owned(value)))
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyValueRefKind::Mssql(value) => {
+                AnyValue(AnyValueKind::Mssql(ValueRef::to_owned(value)))
+            }
+        }
+    }
+
+    fn type_info(&self) -> Option<Cow<'_, AnyTypeInfo>> {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyValueRefKind::Postgres(value) => value.type_info().map(map_type_info),
+
+            #[cfg(feature = "mysql")]
+            AnyValueRefKind::MySql(value) => value.type_info().map(map_type_info),
+
+            #[cfg(feature = "sqlite")]
+            AnyValueRefKind::Sqlite(value) => value.type_info().map(map_type_info),
+
+            #[cfg(feature = "mssql")]
+            AnyValueRefKind::Mssql(value) => value.type_info().map(map_type_info),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyValueRefKind::Postgres(value) => value.is_null(),
+
+            #[cfg(feature = "mysql")]
+            AnyValueRefKind::MySql(value) => value.is_null(),
+
+            #[cfg(feature = "sqlite")]
+            AnyValueRefKind::Sqlite(value) => value.is_null(),
+
+            #[cfg(feature = "mssql")]
+            AnyValueRefKind::Mssql(value) => value.is_null(),
+        }
+    }
+}
+
+fn map_type_info<T>(ty: Cow<'_, T>) -> Cow<'_, AnyTypeInfo>
+where
+    T: Clone,
+    AnyTypeInfo: From<T>,
+{
+    Cow::Owned(ty.into_owned().into())
+}
+
+#[cfg(feature = "postgres")]
+impl<'r> From<PgValueRef<'r>> for AnyValueRef<'r> {
+    fn from(value: PgValueRef<'r>) -> Self {
+        AnyValueRef(AnyValueRefKind::Postgres(value))
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<'r> From<MySqlValueRef<'r>> for AnyValueRef<'r> {
+    fn from(value: MySqlValueRef<'r>) -> Self {
+        AnyValueRef(AnyValueRefKind::MySql(value))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<'r> From<SqliteValueRef<'r>> for AnyValueRef<'r> {
+    fn from(value: SqliteValueRef<'r>) -> Self {
+        AnyValueRef(AnyValueRefKind::Sqlite(value))
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl<'r> From<MssqlValueRef<'r>> for AnyValueRef<'r> {
+    fn from(value: MssqlValueRef<'r>) -> Self {
+        AnyValueRef(AnyValueRefKind::Mssql(value))
+    }
+}
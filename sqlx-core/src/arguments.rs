@@ -1,5 +1,7 @@
 //! Types and traits for passing arguments to SQL queries.
 
+use std::fmt::{self, Write};
+
 use crate::database::{Database, HasArguments};
 use crate::encode::Encode;
 use crate::types::Type;
@@ -16,6 +18,24 @@ pub trait Arguments<'q>: Send + Sized + Default {
     fn add<T>(&mut self, value: T)
     where
         T: 'q + Encode<'q, Self::Database> + Type<Self::Database>;
+
+    /// Writes the placeholder for the most recently added argument in the bind parameter
+    /// syntax of the database (`?` for most SQL flavors, `$N` for PostgreSQL, `@pN` for MSSQL).
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_str("?")
+    }
+
+    /// Returns empty arguments that write placeholders in the same syntax as these.
+    #[doc(hidden)]
+    fn new_like(&self) -> Self {
+        Self::default()
+    }
+
+    /// Renders each value for the statement log.
+    #[doc(hidden)]
+    fn format_values(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 pub trait IntoArguments<'q, DB: HasArguments<'q>>: Sized + Send {
@@ -39,5 +59,252 @@ macro_rules! impl_into_arguments_for_arguments {
     };
 }
 
-// TODO: Impl `IntoArguments` for &[&dyn Encode]
-// TODO: Impl `IntoArguments` for (impl Encode, ...) x16
+/// A value that can be added to the arguments of a query, usable as a trait object.
+///
+/// This is implemented for every type that implements both [`Encode`] and [`Type`] and allows
+/// building the arguments of a query from a slice of values with differing types that is only
+/// known at runtime:
+///
+/// ```rust,ignore
+/// let arguments: &[&dyn Argument<'_, Postgres>] = &[&10_i32, &"foo"];
+///
+/// sqlx::query_with("SELECT * FROM users WHERE id = $1 AND name = $2", arguments)
+/// ```
+pub trait Argument<'q, DB: Database>: Send + Sync {
+    /// Add this value to the end of `arguments`.
+    fn add_to(&'q self, arguments: &mut <DB as HasArguments<'q>>::Arguments);
+}
+
+impl<'q, DB, T> Argument<'q, DB> for T
+where
+    DB: Database,
+    T: 'q + Send + Sync + Encode<'q, DB> + Type<DB>,
+{
+    fn add_to(&'q self, arguments: &mut <DB as HasArguments<'q>>::Arguments) {
+        arguments.add(self);
+    }
+}
+
+impl<'q, DB> IntoArguments<'q, DB> for &'q [&'q dyn Argument<'q, DB>]
+where
+    DB: Database,
+{
+    fn into_arguments(self) -> <DB as HasArguments<'q>>::Arguments {
+        let mut arguments = <DB as HasArguments<'q>>::Arguments::default();
+        arguments.reserve(self.len(), 0);
+
+        for argument in self {
+            argument.add_to(&mut arguments);
+        }
+
+        arguments
+    }
+}
+
+// implement IntoArguments for tuples of types that implement Encode
+// up to tuples of 16 values
+
+macro_rules! impl_into_arguments_for_tuple {
+    ($( ($idx:tt) -> $T:ident );+;) => {
+        impl<'q, DB, $($T,)+> IntoArguments<'q, DB> for ($($T,)+)
+        where
+            DB: Database,
+            $($T: 'q + Send + Encode<'q, DB> + Type<DB>,)+
+        {
+            fn into_arguments(self) -> <DB as HasArguments<'q>>::Arguments {
+                let mut arguments = <DB as HasArguments<'q>>::Arguments::default();
+
+                let size = 0 $(+ <$T as Encode<'q, DB>>::size_hint(&self.$idx))+;
+                arguments.reserve([$($idx),+].len(), size);
+
+                $(arguments.add(self.$idx);)+
+
+                arguments
+            }
+        }
+    };
+}
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+);
+
+impl_into_arguments_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+    (15) -> T16;
+);
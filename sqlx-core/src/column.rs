@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+
+use crate::database::Database;
+use crate::describe;
+
+/// Metadata of a column in the results of a query.
+///
+/// Returned by [`Row::columns`](crate::row::Row::columns) and
+/// [`Row::column`](crate::row::Row::column).
+///
+/// This trait is sealed and cannot be implemented for types outside of SQLx.
+pub trait Column: private_column::Sealed + 'static + Send + Sync + Debug {
+    type Database: Database;
+
+    /// Returns the position of this column in the row, starting at `0`.
+    ///
+    /// Unlike the name, this unambiguously refers to this column when more than one
+    /// column in the row has the same name.
+    fn ordinal(&self) -> usize;
+
+    /// Returns the name of this column, or its alias if it was given one in the query.
+    fn name(&self) -> &str;
+
+    /// Returns the type information of this column, if available.
+    ///
+    /// Some databases do not report a type for every column; SQLite only knows the
+    /// declared type of columns that come directly from a table, and not of expressions
+    /// (`SELECT 2 + 5`). This will return `None` in those cases.
+    fn type_info(&self) -> Option<&<Self::Database as Database>::TypeInfo>;
+
+    /// Creates the column at `ordinal` from the description of a prepared statement.
+    #[doc(hidden)]
+    fn from_describe(ordinal: usize, column: describe::Column<Self::Database>) -> Self
+    where
+        Self: Sized;
+}
+
+// Prevent users from implementing the `Column` trait.
+pub(crate) mod private_column {
+    pub trait Sealed {}
+}
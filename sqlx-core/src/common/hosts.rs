@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+
+use crate::error::BoxDynError;
+
+/// Split the host list out of a multi-host connection URL, as accepted by libpq:
+///
+/// ```text
+/// postgres://user@host1:5432,host2,host3:5433/database
+/// ```
+///
+/// Returns the URL with only the first host, which `url` can parse, and every host of the list
+/// with its port, if any. The list is empty if the URL has a single host.
+pub(crate) fn split_hosts(
+    url: &str,
+) -> Result<(Cow<'_, str>, Vec<(String, Option<u16>)>), BoxDynError> {
+    let authority_start = match url.find("://") {
+        Some(index) => index + 3,
+        None => return Ok((Cow::Borrowed(url), Vec::new())),
+    };
+
+    let authority_end = url[authority_start..]
+        .find(['/', '?', '#'])
+        .map_or(url.len(), |index| authority_start + index);
+
+    // the host list starts after the user info, if any
+    let hosts_start = url[authority_start..authority_end]
+        .rfind('@')
+        .map_or(authority_start, |index| authority_start + index + 1);
+
+    let hosts = &url[hosts_start..authority_end];
+
+    if !hosts.contains(',') {
+        return Ok((Cow::Borrowed(url), Vec::new()));
+    }
+
+    let first = hosts.split(',').next().unwrap_or_default();
+    let single = format!("{}{}{}", &url[..hosts_start], first, &url[authority_end..]);
+
+    let hosts = hosts
+        .split(',')
+        .map(parse_host)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((Cow::Owned(single), hosts))
+}
+
+fn parse_host(host: &str) -> Result<(String, Option<u16>), BoxDynError> {
+    // the port follows the last colon, unless that is part of a bracketed IPv6 address
+    let port_start = match host.rfind(':') {
+        Some(index) if !host[index..].contains(']') => index,
+        _ => return Ok((host.to_owned(), None)),
+    };
+
+    let port = host[port_start + 1..]
+        .parse()
+        .map_err(|_| format!("invalid port in host list: {:?}", host))?;
+
+    Ok((host[..port_start].to_owned(), Some(port)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_hosts;
+
+    #[test]
+    fn it_splits_hosts() {
+        let (url, hosts) =
+            split_hosts("postgres://user:pass@h1:5432,h2,[::1]:5433/db?sslmode=disable").unwrap();
+
+        assert_eq!(url, "postgres://user:pass@h1:5432/db?sslmode=disable");
+        assert_eq!(
+            hosts,
+            vec![
+                ("h1".to_owned(), Some(5432)),
+                ("h2".to_owned(), None),
+                ("[::1]".to_owned(), Some(5433)),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_leaves_single_hosts_alone() {
+        let (url, hosts) = split_hosts("mysql://root@localhost:3306/db").unwrap();
+
+        assert_eq!(url, "mysql://root@localhost:3306/db");
+        assert!(hosts.is_empty());
+    }
+}
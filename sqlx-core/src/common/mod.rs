@@ -1,3 +1,13 @@
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+mod hosts;
 mod statement_cache;
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "mssql"))]
+mod timeout;
 
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+pub(crate) use hosts::split_hosts;
 pub(crate) use statement_cache::StatementCache;
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+pub(crate) use timeout::with_timeout;
+#[cfg(feature = "mssql")]
+pub(crate) use timeout::Deadline;
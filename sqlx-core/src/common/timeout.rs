@@ -0,0 +1,83 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use futures_core::Stream;
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use futures_util::{future::Either, pin_mut, StreamExt, TryStreamExt};
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use crate::error::Error;
+
+/// The point in time by which a query with a [timeout](crate::query::Query::timeout) must
+/// have completed.
+pub(crate) struct Deadline(Option<Instant>);
+
+impl Deadline {
+    pub(crate) fn after(timeout: Option<Duration>) -> Self {
+        Self(timeout.map(|timeout| Instant::now() + timeout))
+    }
+
+    /// Waits for `future`, or returns `None` if the deadline passes first, in which case the
+    /// future is dropped.
+    pub(crate) async fn wait<F: Future>(&self, future: F) -> Option<F::Output> {
+        match self.0 {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+
+                sqlx_rt::timeout(remaining, future).await.ok()
+            }
+
+            None => Some(future.await),
+        }
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+/// Limits the time spent waiting for the results of a query to `timeout`.
+///
+/// When the timeout elapses, `interrupt` is run to stop the query on the server; the rest of
+/// `stream` is then read and discarded, so the connection is left ready for its next query,
+/// and the stream ends with [`Error::QueryTimedOut`].
+///
+/// `stream` is never dropped in the middle of a read; this requires `interrupt` to work
+/// without the connection, which is busy receiving the results.
+pub(crate) fn with_timeout<'e, T, S, I>(
+    stream: S,
+    timeout: Option<Duration>,
+    interrupt: I,
+) -> impl Stream<Item = Result<T, Error>> + 'e
+where
+    T: 'e + Send,
+    S: 'e + Send + Stream<Item = Result<T, Error>>,
+    I: 'e + Send + Future<Output = Result<(), Error>>,
+{
+    if timeout.is_none() {
+        return Either::Left(stream);
+    }
+
+    Either::Right(try_stream! {
+        let deadline = Deadline::after(timeout);
+
+        pin_mut!(stream);
+
+        while let Some(next) = deadline.wait(stream.try_next()).await {
+            match next? {
+                Some(v) => {
+                    r#yield!(v);
+                }
+
+                None => return Ok(()),
+            }
+        }
+
+        if let Err(error) = interrupt.await {
+            log::warn!("failed to interrupt a query that timed out: {}", error);
+        }
+
+        // the query either fails because it was interrupted or runs to its end
+        while stream.next().await.is_some() {}
+
+        Err(Error::QueryTimedOut)
+    })
+}
@@ -1,11 +1,15 @@
+use std::fmt::{self, Debug, Formatter};
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
 use futures_core::future::BoxFuture;
 use futures_core::Future;
+use log::LevelFilter;
 
 use crate::database::{Database, HasStatementCache};
 use crate::error::{BoxDynError, Error};
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions};
 
 /// Represents a single database connection.
 pub trait Connection: Send {
@@ -28,7 +32,21 @@ pub trait Connection: Send {
     where
         Self: Sized,
     {
-        Transaction::begin(self)
+        Transaction::begin(self, TransactionOptions::default())
+    }
+
+    /// Begin a new transaction with the given isolation level, access mode and deferrability.
+    ///
+    /// Behaves like [`begin`](#method.begin) when called within an active transaction; a
+    /// savepoint cannot change the options of the transaction it belongs to.
+    fn begin_with(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database, Self>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin(self, options)
     }
 
     /// Execute the function inside a transaction.
@@ -81,6 +99,12 @@ pub trait Connection: Send {
         Box::pin(async move { Ok(()) })
     }
 
+    /// Changes how the statements run on this connection are logged.
+    ///
+    /// The settings start out as those given to the connect options of the connection. A
+    /// pooled connection keeps its settings when it is returned to the pool.
+    fn set_logging(&mut self, settings: LogSettings);
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>>;
 
@@ -119,3 +143,104 @@ pub trait Connect: Sized + Connection {
     /// Establish a new database connection with the provided options.
     fn connect_with(options: &Self::Options) -> BoxFuture<'_, Result<Self, Error>>;
 }
+
+/// Controls how the statements run on a connection are logged.
+///
+/// Each statement is logged to the `sqlx::query` target once it completes, with the number
+/// of rows it returned and the time it took. Statements that ran for at least the slow
+/// statement duration are logged at the slow statement level instead of the statement level.
+///
+/// Set these with the `log_*` methods of the connect options of each database, or on an
+/// open connection with [`Connection::set_logging`]:
+///
+/// ```rust,ignore
+/// conn.set_logging(
+///     LogSettings::new()
+///         .log_statements(LevelFilter::Trace)
+///         .log_slow_statements(LevelFilter::Info, Duration::from_millis(100))
+///         .log_arguments(true)
+///         .redact_arguments(|index, value| {
+///             if index == 1 { "<password>".into() } else { value.into() }
+///         }),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct LogSettings {
+    pub(crate) statements_level: LevelFilter,
+    pub(crate) slow_statements_level: LevelFilter,
+    pub(crate) slow_statements_duration: Duration,
+    pub(crate) arguments: bool,
+    pub(crate) redact_arguments: Option<Arc<dyn Fn(usize, &str) -> String + Send + Sync>>,
+}
+
+impl LogSettings {
+    /// Get the default logging settings.
+    ///
+    /// See the source of this method for current defaults.
+    pub fn new() -> Self {
+        Self {
+            // log every statement at `debug`
+            statements_level: LevelFilter::Debug,
+            // and statements that took a second or more at `warn`
+            slow_statements_level: LevelFilter::Warn,
+            slow_statements_duration: Duration::from_secs(1),
+            // bound values may be sensitive and are not logged
+            arguments: false,
+            redact_arguments: None,
+        }
+    }
+
+    /// Set the level at which statements are logged. `LevelFilter::Off` disables logging of
+    /// statements that are not slow.
+    pub fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.statements_level = level;
+        self
+    }
+
+    /// Set the level at which statements that ran for at least `duration` are logged.
+    /// `LevelFilter::Off` disables logging of slow statements.
+    pub fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.slow_statements_level = level;
+        self.slow_statements_duration = duration;
+        self
+    }
+
+    /// Set whether the values bound to a statement are included when it is logged.
+    ///
+    /// Values are rendered from their encoded form: numbers, booleans and text are written
+    /// out while other types are summarized by their SQL type. The values of MSSQL statements
+    /// are not logged.
+    pub fn log_arguments(mut self, log: bool) -> Self {
+        self.arguments = log;
+        self
+    }
+
+    /// Set a function that receives the index and the rendered text of each bound value and
+    /// returns the text to log in its place, to keep passwords and other secrets out of the
+    /// logs. Only used if [`log_arguments`](#method.log_arguments) is enabled.
+    pub fn redact_arguments<F>(mut self, redact: F) -> Self
+    where
+        F: Fn(usize, &str) -> String + 'static + Send + Sync,
+    {
+        self.redact_arguments = Some(Arc::new(redact));
+        self
+    }
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for LogSettings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogSettings")
+            .field("statements_level", &self.statements_level)
+            .field("slow_statements_level", &self.slow_statements_level)
+            .field("slow_statements_duration", &self.slow_statements_duration)
+            .field("arguments", &self.arguments)
+            .field("redact_arguments", &self.redact_arguments.is_some())
+            .finish()
+    }
+}
@@ -3,7 +3,9 @@
 use std::fmt::Debug;
 
 use crate::arguments::Arguments;
+use crate::column::Column;
 use crate::connection::Connect;
+use crate::done::Done;
 use crate::row::Row;
 use crate::transaction::TransactionManager;
 use crate::type_info::TypeInfo;
@@ -31,6 +33,12 @@ pub trait Database:
     /// The concrete `Row` implementation for this database.
     type Row: Row<Database = Self>;
 
+    /// The concrete `Done` implementation for this database.
+    type Done: Done<Database = Self>;
+
+    /// The concrete `Column` implementation for this database.
+    type Column: Column<Database = Self>;
+
     /// The concrete `TypeInfo` implementation for this database.
     type TypeInfo: TypeInfo;
 
@@ -72,7 +80,7 @@ pub trait HasArguments<'q> {
     type Arguments: Arguments<'q, Database = Self::Database>;
 
     /// The concrete type used as a buffer for arguments while encoding.
-    type ArgumentBuffer: Default;
+    type ArgumentBuffer;
 }
 
 pub trait HasStatementCache {}
@@ -0,0 +1,16 @@
+use crate::database::Database;
+
+/// The result of executing a query that is not a result set of rows.
+///
+/// Returned by [`Executor::execute`](crate::executor::Executor::execute) and on the
+/// left side of the stream returned by
+/// [`Executor::fetch_many`](crate::executor::Executor::fetch_many).
+///
+/// Each database driver provides its own type with any additional information
+/// it has available, for example, the ID of the last inserted row.
+pub trait Done: 'static + Sized + Send + Sync + Default + Extend<Self> {
+    type Database: Database;
+
+    /// Returns the number of rows affected by an `UPDATE`, `INSERT`, or `DELETE`.
+    fn rows_affected(&self) -> u64;
+}
@@ -91,6 +91,16 @@ pub enum Error {
     /// [`Pool::close`]: crate::pool::Pool::close
     #[error("attempted to acquire a connection on a closed pool")]
     PoolClosed,
+
+    /// A query did not complete within the timeout set with [`Query::timeout`].
+    ///
+    /// [`Query::timeout`]: crate::query::Query::timeout
+    #[error("query timed out")]
+    QueryTimedOut,
+
+    /// The arguments of a query are not valid for the database it is executed on.
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
 }
 
 impl Error {
@@ -108,6 +118,35 @@ impl Error {
         }
     }
 
+    /// Returns `true` if the error is expected to go away on its own, so that the operation
+    /// may succeed if it is tried again later.
+    ///
+    /// This covers connections that were refused, reset or dropped, and errors returned by a
+    /// database that is starting up, shutting down, overloaded or busy, as classified by
+    /// [`DatabaseError::is_transient`]. Conflicts between concurrent transactions are not
+    /// considered transient; see [`DatabaseError::kind`] instead.
+    ///
+    /// The pool retries transient errors while opening a connection.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Io(error) => matches!(
+                error.kind(),
+                io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::NotConnected
+                    | io::ErrorKind::BrokenPipe
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::UnexpectedEof
+            ),
+
+            Error::Database(error) => error.is_transient(),
+
+            _ => false,
+        }
+    }
+
     #[allow(dead_code)]
     #[inline]
     pub(crate) fn protocol(err: impl Display) -> Self {
@@ -131,6 +170,34 @@ pub(crate) fn mismatched_types<DB: Database, T: Type<DB>>(ty: &DB::TypeInfo) ->
     .into()
 }
 
+/// The kind of a [`DatabaseError`], independent of the database it came from.
+///
+/// Returned from [`DatabaseError::kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A unique or primary key constraint was violated.
+    UniqueViolation,
+
+    /// A foreign key constraint was violated.
+    ForeignKeyViolation,
+
+    /// A `NOT NULL` constraint was violated.
+    NotNullViolation,
+
+    /// A `CHECK` constraint was violated.
+    CheckViolation,
+
+    /// The transaction could not be serialized with concurrent transactions.
+    SerializationFailure,
+
+    /// The transaction was aborted to resolve a deadlock.
+    Deadlock,
+
+    /// Any other error.
+    Other,
+}
+
 /// An error that was returned from the database.
 pub trait DatabaseError: 'static + Send + Sync + StdError {
     /// The primary, human-readable error message.
@@ -141,6 +208,43 @@ pub trait DatabaseError: 'static + Send + Sync + StdError {
         None
     }
 
+    /// The kind of the error, classified from its database-specific code.
+    ///
+    /// ```rust,ignore
+    /// match sqlx::query("INSERT INTO users (email) VALUES ($1)")
+    ///     .bind(email)
+    ///     .execute(&pool)
+    ///     .await
+    /// {
+    ///     Err(sqlx::Error::Database(e)) if e.kind() == ErrorKind::UniqueViolation => {
+    ///         // the email is already taken
+    ///     }
+    ///
+    ///     // ..
+    /// }
+    /// ```
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+
+    /// The name of the constraint that was violated, if the error is with a specific
+    /// constraint.
+    ///
+    /// Only PostgreSQL reports the constraint separately from the message; the other
+    /// databases always return `None`.
+    fn constraint(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns `true` if the database reported a condition that is expected to go away on its
+    /// own, such as a server that is starting up, shutting down or has too many connections,
+    /// or a database file that is locked by another connection.
+    ///
+    /// See [`Error::is_transient`].
+    fn is_transient(&self) -> bool {
+        false
+    }
+
     #[doc(hidden)]
     fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static);
 
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::future::BoxFuture;
@@ -8,6 +9,7 @@ use futures_util::{future, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use crate::database::{Database, HasArguments};
 use crate::describe::Describe;
 use crate::error::Error;
+use crate::statement::Statement;
 
 /// A type that contains or can provide a database
 /// connection to use for executing queries against the database.
@@ -28,18 +30,22 @@ pub trait Executor<'c>: Send + Debug + Sized {
     type Database: Database;
 
     /// Execute the query and return the total number of rows affected.
-    fn execute<'e, 'q: 'e, E: 'q>(self, query: E) -> BoxFuture<'e, Result<u64, Error>>
+    fn execute<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<<Self::Database as Database>::Done, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
-        self.execute_many(query)
-            .try_fold(0, |acc, x| async move { Ok(acc + x) })
-            .boxed()
+        self.execute_many(query).try_collect().boxed()
     }
 
     /// Execute multiple queries and return the rows affected from each query, in a stream.
-    fn execute_many<'e, 'q: 'e, E: 'q>(self, query: E) -> BoxStream<'e, Result<u64, Error>>
+    fn execute_many<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<<Self::Database as Database>::Done, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
@@ -47,7 +53,7 @@ pub trait Executor<'c>: Send + Debug + Sized {
         self.fetch_many(query)
             .try_filter_map(|step| async move {
                 Ok(match step {
-                    Either::Left(rows) => Some(rows),
+                    Either::Left(done) => Some(done),
                     Either::Right(_) => None,
                 })
             })
@@ -78,7 +84,13 @@ pub trait Executor<'c>: Send + Debug + Sized {
     fn fetch_many<'e, 'q: 'e, E: 'q>(
         self,
         query: E,
-    ) -> BoxStream<'e, Result<Either<u64, <Self::Database as Database>::Row>, Error>>
+    ) -> BoxStream<
+        'e,
+        Result<
+            Either<<Self::Database as Database>::Done, <Self::Database as Database>::Row>,
+            Error,
+        >,
+    >
     where
         'c: 'e,
         E: Execute<'q, Self::Database>;
@@ -121,6 +133,23 @@ pub trait Executor<'c>: Send + Debug + Sized {
         'c: 'e,
         E: Execute<'q, Self::Database>;
 
+    /// Prepare the SQL query to inspect the type information of its parameters
+    /// and results, and to execute it any number of times.
+    ///
+    /// An invalid query (e.g., a syntax error or a reference to a missing table) will
+    /// return an error here, before it is ever executed.
+    fn prepare<'e, 'q: 'e>(
+        self,
+        query: &'q str,
+    ) -> BoxFuture<'e, Result<Statement<'q, Self::Database>, Error>>
+    where
+        'c: 'e,
+    {
+        self.describe(query)
+            .map_ok(move |describe| Statement::new(query, describe))
+            .boxed()
+    }
+
     /// Prepare the SQL query and return type information about its parameters
     /// and results.
     ///
@@ -142,6 +171,7 @@ pub trait Executor<'c>: Send + Debug + Sized {
 ///
 ///  * [`&str`]
 ///  * [`Query`]
+///  * `(&str, Option<Arguments>)`
 ///
 pub trait Execute<'q, DB: Database>: Send + Sized {
     /// Returns the query string that will be executed.
@@ -153,6 +183,23 @@ pub trait Execute<'q, DB: Database>: Send + Sized {
     /// prepare the query. Returning `Some(Default::default())` is an empty arguments object that
     /// will be prepared (and cached) before execution.
     fn take_arguments(&mut self) -> Option<<DB as HasArguments<'q>>::Arguments>;
+
+    /// Returns `true` if the prepared statement should be kept in the statement cache of the
+    /// connection, `false` if it should be prepared as a one-off statement that is not cached.
+    ///
+    /// Only has an effect if [`take_arguments`](#tymethod.take_arguments) returns `Some`.
+    #[inline]
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    /// Returns the maximum amount of time the query may run for, if it is limited.
+    ///
+    /// See [`Query::timeout`](crate::query::Query::timeout).
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
 }
 
 // NOTE: `Execute` is explicitly not implemented for String and &String to make it slightly more
@@ -168,3 +215,15 @@ impl<'q, DB: Database> Execute<'q, DB> for &'q str {
         None
     }
 }
+
+impl<'q, DB: Database> Execute<'q, DB> for (&'q str, Option<<DB as HasArguments<'q>>::Arguments>) {
+    #[inline]
+    fn query(&self) -> &'q str {
+        self.0
+    }
+
+    #[inline]
+    fn take_arguments(&mut self) -> Option<<DB as HasArguments<'q>>::Arguments> {
+        self.1.take()
+    }
+}
@@ -19,6 +19,56 @@ use crate::row::Row;
 /// }
 /// ```
 ///
+/// The derive accepts the following attributes:
+///
+///  * `#[sqlx(rename_all = "..")]` on the struct renames every field using one of `lowercase`,
+///    `uppercase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`, `camelCase` or
+///    `PascalCase`.
+///
+///  * `#[sqlx(rename = "..")]` on a field reads it from the given column name.
+///
+///  * `#[sqlx(default)]` on a field uses `Default::default()` if the column is not present
+///    in the row.
+///
+///  * `#[sqlx(flatten)]` on a field builds it with its own `FromRow` implementation from
+///    the same row.
+///
+///  * `#[sqlx(skip)]` on a field does not read it from the row and always uses
+///    `Default::default()`.
+///
+///  * `#[sqlx(try_from = "T")]` on a field decodes the column as `T` and converts it using
+///    `TryFrom<T>`.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// #[sqlx(rename_all = "camelCase")]
+/// struct User {
+///     user_id: i32,
+///
+///     #[sqlx(try_from = "i64")]
+///     login_count: u64,
+///
+///     #[sqlx(default)]
+///     nickname: Option<String>,
+///
+///     #[sqlx(flatten)]
+///     address: Address,
+///
+///     #[sqlx(skip)]
+///     cached_posts: Vec<Post>,
+/// }
+/// ```
+///
+/// Tuple structs are also supported; their fields are read by position instead of by name.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct Pair(i32, String);
+/// ```
+///
+/// Plain tuples of up to 16 elements implement `FromRow` for every [`Row`] and are read
+/// by position in the same way.
+///
 /// [`query_as`]: crate::query_as
 /// [`Row::try_get`]: crate::row::Row::try_get
 pub trait FromRow<'r, R: Row>: Sized {
@@ -26,7 +76,7 @@ pub trait FromRow<'r, R: Row>: Sized {
 }
 
 // implement FromRow for tuples of types that implement Decode
-// up to tuples of 9 values
+// up to tuples of 16 values
 
 macro_rules! impl_from_row_for_tuple {
     ($( ($idx:tt) -> $T:ident );+;) => {
@@ -23,6 +23,10 @@ where
 
     // we read into the read buffer using 100% safe code
     rbuf: BytesMut,
+
+    // how many bytes of the read buffer were filled by a `read_with` that was dropped
+    // before it completed, to be kept by the next call
+    rbuf_filled: usize,
 }
 
 impl<S> BufStream<S>
@@ -34,6 +38,7 @@ where
             stream,
             wbuf: Vec::with_capacity(512),
             rbuf: BytesMut::with_capacity(4096),
+            rbuf_filled: 0,
         }
     }
 
@@ -65,6 +70,8 @@ where
         self.read_with(cnt, ()).await
     }
 
+    // if the future is dropped before it completes, no data is lost as long as the next
+    // read asks for the same number of bytes
     pub async fn read_with<'de, T, C>(&mut self, cnt: usize, context: C) -> Result<T, Error>
     where
         T: Decode<'de, C>,
@@ -72,11 +79,10 @@ where
         // zero-fills the space in the read buffer
         self.rbuf.resize(cnt, 0);
 
-        let mut read = 0;
-        while cnt > read {
+        while cnt > self.rbuf_filled {
             // read in bytes from the stream into the read buffer starting
             // from the offset we last read from
-            let n = self.stream.read(&mut self.rbuf[read..]).await?;
+            let n = self.stream.read(&mut self.rbuf[self.rbuf_filled..]).await?;
 
             if n == 0 {
                 // a zero read when we had space in the read buffer
@@ -87,9 +93,11 @@ where
                 return Err(io::Error::from(io::ErrorKind::ConnectionAborted).into());
             }
 
-            read += n;
+            self.rbuf_filled += n;
         }
 
+        self.rbuf_filled = 0;
+
         T::decode_with(self.rbuf.split_to(cnt).freeze(), context)
     }
 }
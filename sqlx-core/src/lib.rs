@@ -23,6 +23,9 @@ mod ext;
 #[macro_use]
 pub mod error;
 
+#[macro_use]
+mod logging;
+
 #[macro_use]
 pub mod arguments;
 
@@ -37,18 +40,22 @@ pub mod transaction;
 #[macro_use]
 pub mod encode;
 
+pub mod column;
 mod common;
 pub mod database;
 pub mod decode;
 pub mod describe;
+pub mod done;
 pub mod executor;
 pub mod from_row;
 mod io;
 mod net;
 pub mod query;
 pub mod query_as;
+pub mod query_builder;
 pub mod query_scalar;
 pub mod row;
+pub mod statement;
 pub mod type_info;
 pub mod types;
 pub mod value;
@@ -68,3 +75,20 @@ pub mod mysql;
 #[cfg(feature = "mssql")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mssql")))]
 pub mod mssql;
+
+#[cfg(feature = "any")]
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub mod any;
+
+#[cfg(all(
+    feature = "any",
+    not(any(
+        feature = "postgres",
+        feature = "mysql",
+        feature = "sqlite",
+        feature = "mssql",
+    )),
+))]
+compile_error!(
+    "the 'any' feature requires at least one of 'postgres', 'mysql', 'sqlite' or 'mssql' features"
+);
@@ -1,45 +1,233 @@
-use std::time::Duration;
-
-pub(crate) const SLOW_QUERY_THRESHOLD: Duration = Duration::from_secs(1);
-
-/// Logs the query and execution time of a statement as it runs.
-macro_rules! log_execution {
-    ( $query:expr, $block:expr ) => {{
-        // TODO: Log bound parameters
-        let query_string = $query.query_string();
-        let timer = std::time::Instant::now();
-        let result = $block;
-        let elapsed = timer.elapsed();
-        if elapsed >= crate::logging::SLOW_QUERY_THRESHOLD {
-            log::warn!(
-                target: "sqlx::query",
-                "{} ..., elapsed: {:.3?}\n\n{}\n",
-                crate::logging::parse_query_summary(query_string),
-                elapsed,
-                sqlformat::format(
-                    query_string,
-                    &sqlformat::QueryParams::None,
-                    sqlformat::FormatOptions::default()
-                )
-            );
+use std::time::Instant;
+
+use either::Either;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use log::LevelFilter;
+
+use crate::arguments::Arguments;
+use crate::connection::LogSettings;
+use crate::done::Done;
+use crate::error::Error;
+
+/// Runs `$future` in a new `tracing` span if the `tracing` feature is enabled, recording the
+/// error it fails with.
+#[cfg(feature = "tracing")]
+macro_rules! instrument {
+    ($future:expr, $($span:tt)+) => {
+        crate::logging::instrument(
+            $future,
+            tracing::info_span!($($span)+, error = tracing::field::Empty),
+        )
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! instrument {
+    ($future:expr, $($span:tt)+) => {
+        $future
+    };
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) async fn instrument<T>(
+    future: impl std::future::Future<Output = Result<T, Error>>,
+    span: tracing::Span,
+) -> Result<T, Error> {
+    use tracing::Instrument;
+
+    let result = future.instrument(span.clone()).await;
+
+    if let Err(error) = &result {
+        span.record("error", tracing::field::display(error));
+    }
+
+    result
+}
+
+/// Logs a statement, its bound values and the rows it returned or affected once it is
+/// dropped, according to the [`LogSettings`] of the connection that ran it.
+///
+/// With the `tracing` feature, the statement also gets a span that is closed when it is
+/// logged.
+pub(crate) struct QueryLogger<'q> {
+    sql: &'q str,
+    arguments: Option<Vec<String>>,
+    rows_returned: u64,
+    rows_affected: u64,
+    error: Option<String>,
+    start: Instant,
+    settings: LogSettings,
+
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl<'q> QueryLogger<'q> {
+    pub(crate) fn new<A>(sql: &'q str, arguments: Option<&A>, settings: &LogSettings) -> Self
+    where
+        A: Arguments<'q>,
+    {
+        // rendering the values is not free; skip it if nothing would be logged
+        let enabled =
+            is_enabled(settings.statements_level) || is_enabled(settings.slow_statements_level);
+
+        let arguments = if settings.arguments && enabled {
+            let values = arguments.map(Arguments::format_values).unwrap_or_default();
+
+            Some(match &settings.redact_arguments {
+                Some(redact) => values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| redact(index, value))
+                    .collect(),
+
+                None => values,
+            })
         } else {
-            log::debug!(
-                target: "sqlx::query",
-                "{} ..., elapsed: {:.3?}\n\n{}\n",
-                crate::logging::parse_query_summary(query_string),
-                elapsed,
-                sqlformat::format(
-                    query_string,
-                    &sqlformat::QueryParams::None,
-                    sqlformat::FormatOptions::default()
-                )
-            );
+            None
+        };
+
+        Self {
+            sql,
+            arguments,
+            rows_returned: 0,
+            rows_affected: 0,
+            error: None,
+            start: Instant::now(),
+            settings: settings.clone(),
+
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::none(),
+        }
+    }
+
+    /// Open the span of the statement, named after the database `system` and `database`
+    /// following the OpenTelemetry conventions.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn span(mut self, system: &'static str, database: Option<&str>) -> Self {
+        use tracing::field::Empty;
+
+        self.span = tracing::info_span!(
+            "sqlx.query",
+            db.system = system,
+            db.name = Empty,
+            db.statement = self.sql,
+            rows_returned = Empty,
+            rows_affected = Empty,
+            duration = Empty,
+            error = Empty,
+        );
+
+        if let Some(database) = database {
+            self.span.record("db.name", database);
         }
-        result
-    }};
+
+        self
+    }
+
+    /// Counts the rows and records the error of each item of `stream` until it is dropped,
+    /// then logs the statement.
+    pub(crate) fn instrument<'e, S, D, R>(
+        mut self,
+        stream: S,
+    ) -> impl Stream<Item = Result<Either<D, R>, Error>> + 'e
+    where
+        'q: 'e,
+        S: Stream<Item = Result<Either<D, R>, Error>> + 'e,
+        D: Done,
+    {
+        #[cfg(feature = "tracing")]
+        let span = self.span.clone();
+
+        let stream = stream.inspect(move |item| match item {
+            Ok(Either::Left(done)) => self.rows_affected += done.rows_affected(),
+            Ok(Either::Right(_)) => self.rows_returned += 1,
+            Err(error) => self.record_error(error),
+        });
+
+        // enter the span each time the stream is polled, so the events emitted while the
+        // statement runs are attached to it
+        #[cfg(feature = "tracing")]
+        let stream = {
+            let mut stream = Box::pin(stream);
+
+            futures_util::stream::poll_fn(move |cx| {
+                let _entered = span.enter();
+
+                stream.as_mut().poll_next(cx)
+            })
+        };
+
+        stream
+    }
+
+    pub(crate) fn record_error(&mut self, error: &Error) {
+        self.error = Some(error.to_string());
+    }
+}
+
+impl Drop for QueryLogger<'_> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+
+        // the statement is logged in the span
+        #[cfg(feature = "tracing")]
+        let _entered = {
+            use tracing::field::{debug, display};
+
+            self.span.record("rows_returned", self.rows_returned);
+            self.span.record("rows_affected", self.rows_affected);
+            self.span.record("duration", debug(elapsed));
+
+            if let Some(error) = &self.error {
+                self.span.record("error", display(error));
+            }
+
+            self.span.enter()
+        };
+
+        let level = if elapsed >= self.settings.slow_statements_duration {
+            self.settings.slow_statements_level
+        } else {
+            self.settings.statements_level
+        };
+
+        let level = match level.to_level() {
+            Some(level) if is_enabled(level.to_level_filter()) => level,
+            _ => return,
+        };
+
+        let arguments = match &self.arguments {
+            Some(values) => format!("\narguments: [{}]", values.join(", ")),
+            None => String::new(),
+        };
+
+        let error = match &self.error {
+            Some(error) => format!("\nerror: {}", error),
+            None => String::new(),
+        };
+
+        log::log!(
+            target: "sqlx::query",
+            level,
+            "{} ...; rows returned: {}, rows affected: {}, elapsed: {:.3?}\n\n{}\n{}{}",
+            parse_query_summary(self.sql),
+            self.rows_returned,
+            self.rows_affected,
+            elapsed,
+            self.sql,
+            arguments,
+            error,
+        );
+    }
+}
+
+fn is_enabled(level: LevelFilter) -> bool {
+    level != LevelFilter::Off && level <= log::max_level()
 }
 
-pub(crate) fn parse_query_summary(query: &str) -> String {
+fn parse_query_summary(query: &str) -> String {
     // For now, just take the first 3 words
     query
         .split_whitespace()
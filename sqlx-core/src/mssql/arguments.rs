@@ -1,8 +1,13 @@
+use std::fmt::{self, Write};
+
+use bytes::{Buf, Bytes};
+
 use crate::arguments::Arguments;
 use crate::encode::Encode;
 use crate::mssql::database::Mssql;
-use crate::mssql::io::MssqlBufMutExt;
+use crate::mssql::io::{MssqlBufExt, MssqlBufMutExt};
 use crate::mssql::protocol::rpc::StatusFlags;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
 use crate::types::Type;
 
 #[derive(Default)]
@@ -108,4 +113,85 @@ impl<'q> Arguments<'q> for MssqlArguments {
         ty.0.put(data); // [TYPE_INFO]
         ty.0.put_value(data, value); // [ParamLenData]
     }
+
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "@p{}", self.ordinal)
+    }
+
+    fn format_values(&self) -> Vec<String> {
+        let mut buf = Bytes::copy_from_slice(&self.data);
+        let mut values = Vec::with_capacity(self.ordinal);
+
+        while buf.has_remaining() {
+            // every value is written as an RPC parameter
+            //  [ParamName] [StatusFlags] [TYPE_INFO] [ParamLenData]
+            let value = buf.get_b_varchar().and_then(|_| {
+                buf.advance(1);
+
+                let ty = TypeInfo::get(&mut buf)?;
+                let value = ty.get_value(&mut buf);
+
+                Ok(format_value(&ty, value))
+            });
+
+            match value {
+                Ok(value) => values.push(value),
+
+                // unreachable as we wrote the parameters ourselves
+                Err(_) => break,
+            }
+        }
+
+        values
+    }
+}
+
+// renders the common scalar types
+fn format_value(ty: &TypeInfo, value: Option<Bytes>) -> String {
+    let mut value = match value {
+        // `None` is sent with the NULL type, whatever its type in Rust
+        Some(_) if ty.ty == DataType::Null => return "NULL".to_owned(),
+        Some(value) => value,
+        None => return "NULL".to_owned(),
+    };
+
+    match (ty.ty, value.len()) {
+        (DataType::Bit, 1) | (DataType::BitN, 1) => (value[0] != 0).to_string(),
+        (DataType::TinyInt, 1) | (DataType::IntN, 1) => value.get_i8().to_string(),
+        (DataType::SmallInt, 2) | (DataType::IntN, 2) => value.get_i16_le().to_string(),
+        (DataType::Int, 4) | (DataType::IntN, 4) => value.get_i32_le().to_string(),
+        (DataType::BigInt, 8) | (DataType::IntN, 8) => value.get_i64_le().to_string(),
+        (DataType::Real, 4) | (DataType::FloatN, 4) => value.get_f32_le().to_string(),
+        (DataType::Float, 8) | (DataType::FloatN, 8) => value.get_f64_le().to_string(),
+
+        (DataType::NVarChar, len) | (DataType::NChar, len) => match value.get_utf16_str(len / 2) {
+            Ok(value) => format!("{:?}", value),
+            Err(_) => format!("<{} bytes of {:?}>", len, ty.ty),
+        },
+
+        (_, len) => format!("<{} bytes of {:?}>", len, ty.ty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MssqlArguments;
+    use crate::arguments::Arguments;
+
+    #[test]
+    fn it_formats_values() {
+        let mut arguments = MssqlArguments::default();
+
+        arguments.add(10_i32);
+        arguments.add("O'Brien");
+        arguments.add(None::<i64>);
+        arguments.add(true);
+        arguments.add(1.5_f64);
+        arguments.add(None::<String>);
+
+        assert_eq!(
+            arguments.format_values(),
+            vec!["10", "\"O'Brien\"", "NULL", "true", "1.5", "NULL"]
+        );
+    }
 }
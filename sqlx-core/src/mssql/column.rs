@@ -0,0 +1,41 @@
+use crate::column::Column;
+use crate::describe;
+use crate::ext::ustr::UStr;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo as ProtocolTypeInfo};
+use crate::mssql::{Mssql, MssqlTypeInfo};
+
+#[derive(Debug, Clone)]
+pub struct MssqlColumn {
+    pub(crate) ordinal: usize,
+    pub(crate) name: UStr,
+    pub(crate) type_info: MssqlTypeInfo,
+}
+
+impl crate::column::private_column::Sealed for MssqlColumn {}
+
+impl Column for MssqlColumn {
+    type Database = Mssql;
+
+    fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn type_info(&self) -> Option<&MssqlTypeInfo> {
+        Some(&self.type_info)
+    }
+
+    fn from_describe(ordinal: usize, column: describe::Column<Mssql>) -> Self {
+        MssqlColumn {
+            ordinal,
+            name: column.name.into(),
+            // MSSQL describes the type of every column
+            type_info: column
+                .type_info
+                .unwrap_or_else(|| MssqlTypeInfo(ProtocolTypeInfo::new(DataType::Null, 0))),
+        }
+    }
+}
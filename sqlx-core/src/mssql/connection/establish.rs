@@ -74,6 +74,12 @@ impl MssqlConnection {
             }
         }
 
-        Ok(Self { stream })
+        Ok(Self {
+            stream,
+            log_settings: options.log_settings.clone(),
+            restore_isolation: None,
+            #[cfg(feature = "tracing")]
+            database: Some(options.database.clone()),
+        })
     }
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -5,16 +7,18 @@ use futures_util::TryStreamExt;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::common::Deadline;
 use crate::describe::{Column, Describe};
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::logging::QueryLogger;
 use crate::mssql::protocol::col_meta_data::Flags;
 use crate::mssql::protocol::done::Status;
 use crate::mssql::protocol::message::Message;
 use crate::mssql::protocol::packet::PacketType;
 use crate::mssql::protocol::rpc::{OptionFlags, Procedure, RpcRequest};
 use crate::mssql::protocol::sql_batch::SqlBatch;
-use crate::mssql::{Mssql, MssqlArguments, MssqlConnection, MssqlRow, MssqlTypeInfo};
+use crate::mssql::{Mssql, MssqlArguments, MssqlConnection, MssqlDone, MssqlRow, MssqlTypeInfo};
 
 impl MssqlConnection {
     async fn run(&mut self, query: &str, arguments: Option<MssqlArguments>) -> Result<(), Error> {
@@ -68,23 +72,44 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
     fn fetch_many<'e, 'q: 'e, E: 'q>(
         self,
         mut query: E,
-    ) -> BoxStream<'e, Result<Either<u64, MssqlRow>, Error>>
+    ) -> BoxStream<'e, Result<Either<MssqlDone, MssqlRow>, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
         let s = query.query();
         let arguments = query.take_arguments();
+        let timeout = query.timeout();
+        let logger = QueryLogger::new(s, arguments.as_ref(), &self.log_settings);
+
+        #[cfg(feature = "tracing")]
+        let logger = logger.span("mssql", self.database.as_deref());
+
+        Box::pin(logger.instrument(try_stream! {
+            let deadline = Deadline::after(timeout);
 
-        Box::pin(try_stream! {
             self.run(s, arguments).await?;
 
             loop {
-                let message = self.stream.recv_message().await?;
+                // the server is asked to stop the query over the same connection, so the
+                // connection cannot be left busy reading like in other drivers
+                let message = match deadline.wait(self.stream.recv_message()).await {
+                    Some(message) => message?,
+
+                    None => {
+                        self.stream.cancel().await?;
+
+                        return Err(Error::QueryTimedOut);
+                    }
+                };
 
                 match message {
                     Message::Row(row) => {
-                        r#yield!(Either::Right(MssqlRow { row }));
+                        r#yield!(Either::Right(MssqlRow {
+                            row,
+                            columns: Arc::clone(&self.stream.row_columns),
+                            column_names: Arc::clone(&self.stream.column_names),
+                        }));
                     }
 
                     Message::Done(done) | Message::DoneProc(done) => {
@@ -93,7 +118,9 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
                         }
 
                         if done.status.contains(Status::DONE_COUNT) {
-                            r#yield!(Either::Left(done.affected_rows));
+                            r#yield!(Either::Left(MssqlDone {
+                                rows_affected: done.affected_rows,
+                            }));
                         }
 
                         if !done.status.contains(Status::DONE_MORE) {
@@ -103,7 +130,9 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
 
                     Message::DoneInProc(done) => {
                         if done.status.contains(Status::DONE_COUNT) {
-                            r#yield!(Either::Left(done.affected_rows));
+                            r#yield!(Either::Left(MssqlDone {
+                                rows_affected: done.affected_rows,
+                            }));
                         }
                     }
 
@@ -112,7 +141,7 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
             }
 
             Ok(())
-        })
+        }))
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
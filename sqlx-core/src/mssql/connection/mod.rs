@@ -4,7 +4,7 @@ use std::net::Shutdown;
 use futures_core::future::BoxFuture;
 use futures_util::{future::ready, FutureExt, TryFutureExt};
 
-use crate::connection::{Connect, Connection};
+use crate::connection::{Connect, Connection, LogSettings};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::mssql::connection::stream::MssqlStream;
@@ -16,6 +16,17 @@ mod stream;
 
 pub struct MssqlConnection {
     pub(crate) stream: MssqlStream,
+
+    // how the statements run on this connection are logged
+    pub(crate) log_settings: LogSettings,
+
+    // the isolation level of the session before the open transaction changed it,
+    // which must be restored once it ends
+    pub(crate) restore_isolation: Option<&'static str>,
+
+    // the name of the database, reported in the spans of statements
+    #[cfg(feature = "tracing")]
+    pub(crate) database: Option<String>,
 }
 
 impl Debug for MssqlConnection {
@@ -37,6 +48,10 @@ impl Connection for MssqlConnection {
         self.execute("/* SQLx ping */").map_ok(|_| ()).boxed()
     }
 
+    fn set_logging(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.stream.wait_until_ready().boxed()
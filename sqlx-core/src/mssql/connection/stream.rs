@@ -1,9 +1,12 @@
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 use bytes::Bytes;
+use hashbrown::HashMap;
 use sqlx_rt::TcpStream;
 
 use crate::error::Error;
+use crate::ext::ustr::UStr;
 use crate::io::{BufStream, Encode};
 use crate::mssql::protocol::col_meta_data::{ColMetaData, ColumnData};
 use crate::mssql::protocol::done::{Done, Status as DoneStatus};
@@ -17,7 +20,7 @@ use crate::mssql::protocol::packet::{PacketHeader, PacketType, Status};
 use crate::mssql::protocol::return_status::ReturnStatus;
 use crate::mssql::protocol::return_value::ReturnValue;
 use crate::mssql::protocol::row::Row;
-use crate::mssql::{MssqlConnectOptions, MssqlDatabaseError};
+use crate::mssql::{MssqlColumn, MssqlConnectOptions, MssqlDatabaseError, MssqlTypeInfo};
 use crate::net::MaybeTlsStream;
 
 pub(crate) struct MssqlStream {
@@ -33,9 +36,18 @@ pub(crate) struct MssqlStream {
     // current TabularResult from the server that we are iterating over
     response: Option<(PacketHeader, Bytes)>,
 
+    // header of the packet being received, kept until its payload is read in case
+    // `recv_packet` is dropped in between
+    header: Option<PacketHeader>,
+
     // most recent column data from ColMetaData
     // we need to store this as its needed when decoding <Row>
     pub(crate) columns: Vec<ColumnData>,
+
+    // public column information and the index of the column name to its position,
+    // rebuilt on each ColMetaData
+    pub(crate) row_columns: Arc<Vec<MssqlColumn>>,
+    pub(crate) column_names: Arc<HashMap<UStr, usize>>,
 }
 
 impl MssqlStream {
@@ -47,7 +59,10 @@ impl MssqlStream {
         Ok(Self {
             inner,
             columns: Vec::new(),
+            row_columns: Default::default(),
+            column_names: Default::default(),
             response: None,
+            header: None,
             pending_done_count: 0,
             transaction_descriptor: 0,
         })
@@ -87,20 +102,31 @@ impl MssqlStream {
     pub(super) async fn recv_packet(&mut self) -> Result<(PacketHeader, Bytes), Error> {
         // TODO: Support packet chunking for large packet sizes
 
-        let header: PacketHeader = self.inner.read(8).await?;
+        let header = match self.header {
+            Some(header) => header,
 
-        // NOTE: From what I can tell, the response type from the server should ~always~
-        //       be TabularResult. Here we expect that and die otherwise.
-        if !matches!(header.r#type, PacketType::TabularResult) {
-            return Err(err_protocol!(
-                "received unexpected packet: {:?}",
-                header.r#type
-            ));
-        }
+            None => {
+                let header: PacketHeader = self.inner.read(8).await?;
+
+                // NOTE: From what I can tell, the response type from the server should ~always~
+                //       be TabularResult. Here we expect that and die otherwise.
+                if !matches!(header.r#type, PacketType::TabularResult) {
+                    return Err(err_protocol!(
+                        "received unexpected packet: {:?}",
+                        header.r#type
+                    ));
+                }
+
+                self.header = Some(header);
+                header
+            }
+        };
 
         let payload_len = (header.length - 8) as usize;
         let payload: Bytes = self.inner.read(payload_len).await?;
 
+        self.header = None;
+
         Ok((header, payload))
     }
 
@@ -160,6 +186,26 @@ impl MssqlStream {
                         // NOTE: there isn't anything to return as the data gets
                         //       consumed by the stream for use in subsequent Row decoding
                         ColMetaData::get(buf, &mut self.columns)?;
+
+                        self.row_columns = Arc::new(
+                            self.columns
+                                .iter()
+                                .enumerate()
+                                .map(|(ordinal, col)| MssqlColumn {
+                                    ordinal,
+                                    name: UStr::new(&col.col_name),
+                                    type_info: MssqlTypeInfo(col.type_info.clone()),
+                                })
+                                .collect(),
+                        );
+
+                        self.column_names = Arc::new(
+                            self.row_columns
+                                .iter()
+                                .map(|col| (col.name.clone(), col.ordinal))
+                                .collect(),
+                        );
+
                         continue;
                     }
                 };
@@ -178,7 +224,7 @@ impl MssqlStream {
 
     pub(crate) fn handle_error<T>(&mut self, error: ProtocolError) -> Result<T, Error> {
         // error is sent _instead_ of a done
-        self.pending_done_count -= 1;
+        self.pending_done_count = self.pending_done_count.saturating_sub(1);
         Err(MssqlDatabaseError(error).into())
     }
 
@@ -200,6 +246,35 @@ impl MssqlStream {
 
         Ok(())
     }
+
+    // interrupts the request the server is processing with an attention signal and discards
+    // everything up to the acknowledgement of the server
+    pub(crate) async fn cancel(&mut self) -> Result<(), Error> {
+        self.write_packet(PacketType::AttentionSignal, &[][..] as &[u8]);
+        self.flush().await?;
+
+        loop {
+            match self.recv_message().await {
+                Ok(Message::Done(done))
+                | Ok(Message::DoneProc(done))
+                | Ok(Message::DoneInProc(done))
+                    if done.status.contains(DoneStatus::DONE_ATTN) =>
+                {
+                    break;
+                }
+
+                // the interrupted request may fail before the acknowledgement
+                Ok(_) | Err(Error::Database(_)) => {}
+
+                Err(error) => return Err(error),
+            }
+        }
+
+        // the acknowledgement comes after the responses to every request sent before it
+        self.pending_done_count = 0;
+
+        Ok(())
+    }
 }
 
 impl Deref for MssqlStream {
@@ -1,7 +1,7 @@
 use crate::database::{Database, HasArguments, HasValueRef};
 use crate::mssql::{
-    MssqlArguments, MssqlConnection, MssqlRow, MssqlTransactionManager, MssqlTypeInfo, MssqlValue,
-    MssqlValueRef,
+    MssqlArguments, MssqlColumn, MssqlConnection, MssqlDone, MssqlRow, MssqlTransactionManager,
+    MssqlTypeInfo, MssqlValue, MssqlValueRef,
 };
 
 /// MSSQL database driver.
@@ -15,6 +15,10 @@ impl Database for Mssql {
 
     type Row = MssqlRow;
 
+    type Done = MssqlDone;
+
+    type Column = MssqlColumn;
+
     type TypeInfo = MssqlTypeInfo;
 
     type Value = MssqlValue;
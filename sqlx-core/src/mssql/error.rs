@@ -1,7 +1,8 @@
+use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::fmt::{self, Debug, Display, Formatter};
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, ErrorKind};
 use crate::mssql::protocol::error::Error;
 
 /// An error returned from the MSSQL database.
@@ -35,6 +36,51 @@ impl DatabaseError for MssqlDatabaseError {
         &self.0.message
     }
 
+    /// The [number](https://docs.microsoft.com/en-us/sql/relational-databases/errors-events/database-engine-events-and-errors)
+    /// of the error.
+    fn code(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Owned(self.0.number.to_string()))
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self.0.number {
+            // violation of a unique index or of a unique or primary key constraint
+            2601 | 2627 => ErrorKind::UniqueViolation,
+
+            // 547 is raised for both foreign key and check constraints
+            547 if self.0.message.contains("FOREIGN KEY") => ErrorKind::ForeignKeyViolation,
+            547 if self.0.message.contains("CHECK") => ErrorKind::CheckViolation,
+
+            // cannot insert the value NULL into a column
+            515 => ErrorKind::NotNullViolation,
+
+            // chosen as the deadlock victim
+            1205 => ErrorKind::Deadlock,
+
+            // snapshot isolation update conflict
+            3960 => ErrorKind::SerializationFailure,
+
+            _ => ErrorKind::Other,
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        // https://docs.microsoft.com/en-us/azure/azure-sql/database/troubleshoot-common-errors-issues
+        matches!(
+            self.0.number,
+            // the connection was closed or reset by the server
+            233 | 10053 | 10054 | 10060
+                // the database is being recovered, or a login waited too long for recovery
+                | 922 | 4221
+                // the database is not currently available
+                | 40143 | 40197 | 40613
+                // the service is busy or has reached its resource limits
+                | 10928 | 10929 | 40501
+                // the server is being reconfigured
+                | 49918 | 49919 | 49920
+        )
+    }
+
     #[doc(hidden)]
     fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
@@ -1,8 +1,10 @@
 //! Microsoft SQL (MSSQL) database driver.
 
 mod arguments;
+mod column;
 mod connection;
 mod database;
+mod done;
 mod error;
 mod io;
 mod options;
@@ -14,8 +16,10 @@ pub mod types;
 mod value;
 
 pub use arguments::MssqlArguments;
+pub use column::MssqlColumn;
 pub use connection::MssqlConnection;
 pub use database::Mssql;
+pub use done::MssqlDone;
 pub use error::MssqlDatabaseError;
 pub use options::MssqlConnectOptions;
 pub use row::MssqlRow;
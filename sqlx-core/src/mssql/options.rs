@@ -1,7 +1,10 @@
 use std::str::FromStr;
+use std::time::Duration;
 
+use log::LevelFilter;
 use url::Url;
 
+use crate::connection::LogSettings;
 use crate::error::BoxDynError;
 
 #[derive(Debug, Clone)]
@@ -11,6 +14,7 @@ pub struct MssqlConnectOptions {
     pub(crate) username: String,
     pub(crate) database: String,
     pub(crate) password: Option<String>,
+    pub(crate) log_settings: LogSettings,
 }
 
 impl Default for MssqlConnectOptions {
@@ -27,6 +31,7 @@ impl MssqlConnectOptions {
             database: String::from("master"),
             username: String::from("sa"),
             password: None,
+            log_settings: LogSettings::new(),
         }
     }
 
@@ -54,6 +59,41 @@ impl MssqlConnectOptions {
         self.database = database.to_owned();
         self
     }
+
+    /// Sets the level at which the statements run on the connection are logged.
+    ///
+    /// Statements are logged at `Debug` by default. See [`LogSettings`].
+    pub fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.log_settings = self.log_settings.log_statements(level);
+        self
+    }
+
+    /// Sets the level at which statements that ran for at least `duration` are logged.
+    ///
+    /// Statements that took a second or more are logged at `Warn` by default.
+    pub fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.log_settings = self.log_settings.log_slow_statements(level, duration);
+        self
+    }
+
+    /// Sets whether the values bound to a statement are logged along with it.
+    ///
+    /// Values are not logged by default. See [`LogSettings::log_arguments`].
+    pub fn log_arguments(mut self, log: bool) -> Self {
+        self.log_settings = self.log_settings.log_arguments(log);
+        self
+    }
+
+    /// Sets a function that returns the text to log in place of each bound value.
+    ///
+    /// See [`LogSettings::redact_arguments`].
+    pub fn redact_arguments<F>(mut self, redact: F) -> Self
+    where
+        F: Fn(usize, &str) -> String + 'static + Send + Sync,
+    {
+        self.log_settings = self.log_settings.redact_arguments(redact);
+        self
+    }
 }
 
 impl FromStr for MssqlConnectOptions {
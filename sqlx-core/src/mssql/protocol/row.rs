@@ -7,7 +7,6 @@ use crate::mssql::MssqlTypeInfo;
 
 #[derive(Debug)]
 pub(crate) struct Row {
-    // TODO: Column names?
     // FIXME: Columns Vec should be an Arc<_>
     pub(crate) column_types: Vec<MssqlTypeInfo>,
     pub(crate) values: Vec<Option<Bytes>>,
@@ -1,10 +1,17 @@
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+
 use crate::error::Error;
+use crate::ext::ustr::UStr;
 use crate::mssql::protocol::row::Row as ProtocolRow;
-use crate::mssql::{Mssql, MssqlValueRef};
+use crate::mssql::{Mssql, MssqlColumn, MssqlValueRef};
 use crate::row::{ColumnIndex, Row};
 
 pub struct MssqlRow {
     pub(crate) row: ProtocolRow,
+    pub(crate) columns: Arc<Vec<MssqlColumn>>,
+    pub(crate) column_names: Arc<HashMap<UStr, usize>>,
 }
 
 impl crate::row::private_row::Sealed for MssqlRow {}
@@ -17,6 +24,10 @@ impl Row for MssqlRow {
         self.row.values.len()
     }
 
+    fn columns(&self) -> &[MssqlColumn] {
+        &self.columns
+    }
+
     fn try_get_raw<I>(&self, index: I) -> Result<MssqlValueRef<'_>, Error>
     where
         I: ColumnIndex<Self>,
@@ -30,3 +41,12 @@ impl Row for MssqlRow {
         Ok(value)
     }
 }
+
+impl ColumnIndex<MssqlRow> for &'_ str {
+    fn index(&self, row: &MssqlRow) -> Result<usize, Error> {
+        row.column_names
+            .get(*self)
+            .ok_or_else(|| Error::ColumnNotFound((*self).into()))
+            .copied()
+    }
+}
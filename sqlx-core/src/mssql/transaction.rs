@@ -7,7 +7,8 @@ use crate::executor::Executor;
 use crate::mssql::protocol::packet::PacketType;
 use crate::mssql::protocol::sql_batch::SqlBatch;
 use crate::mssql::{Mssql, MssqlConnection};
-use crate::transaction::TransactionManager;
+use crate::row::Row;
+use crate::transaction::{TransactionManager, TransactionOptions};
 
 /// Implementation of [`TransactionManager`] for MSSQL.
 pub struct MssqlTransactionManager;
@@ -15,15 +16,65 @@ pub struct MssqlTransactionManager;
 impl TransactionManager for MssqlTransactionManager {
     type Database = Mssql;
 
-    fn begin(conn: &mut MssqlConnection, depth: usize) -> BoxFuture<'_, Result<(), Error>> {
+    fn begin(
+        conn: &mut MssqlConnection,
+        depth: usize,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
-            let query = if depth == 0 {
-                Cow::Borrowed("BEGIN TRAN ")
-            } else {
-                Cow::Owned(format!("SAVE TRAN _sqlx_savepoint_{}", depth))
-            };
+            if depth > 0 {
+                conn.execute(&*format!("SAVE TRAN _sqlx_savepoint_{}", depth))
+                    .await?;
+
+                return Ok(());
+            }
+
+            match options.isolation {
+                // NOTE: the isolation level stays in effect for the rest of the session, so the
+                //       level before the transaction is restored when it ends
+                Some(isolation) if conn.restore_isolation.is_none() => {
+                    let query = format!(
+                        "SELECT transaction_isolation_level FROM sys.dm_exec_sessions \
+                         WHERE session_id = @@SPID; \
+                         SET TRANSACTION ISOLATION LEVEL {}; BEGIN TRAN ",
+                        isolation.as_sql()
+                    );
+
+                    // executed as a batch; in a parameterized query the level would only last
+                    // until the end of the query
+                    let rows = conn.fetch_all(&*query).await?;
+                    let previous: Option<i16> =
+                        rows.first().map(|row| row.try_get(0)).transpose()?;
+
+                    conn.restore_isolation = Some(isolation_level_sql(previous.unwrap_or(0)));
+                }
 
-            conn.execute(&*query).await?;
+                // the level to restore is already known if the rollback of an earlier
+                // transaction was started without waiting for it to restore the level
+                Some(isolation) => {
+                    conn.execute(&*format!(
+                        "SET TRANSACTION ISOLATION LEVEL {}; BEGIN TRAN ",
+                        isolation.as_sql()
+                    ))
+                    .await?;
+                }
+
+                None => match conn.restore_isolation {
+                    Some(previous) => {
+                        conn.execute(&*format!(
+                            "SET TRANSACTION ISOLATION LEVEL {}; BEGIN TRAN ",
+                            previous
+                        ))
+                        .await?;
+
+                        conn.restore_isolation = None;
+                    }
+
+                    None => {
+                        conn.execute("BEGIN TRAN ").await?;
+                    }
+                },
+            }
 
             Ok(())
         })
@@ -33,7 +84,10 @@ impl TransactionManager for MssqlTransactionManager {
         Box::pin(async move {
             if depth == 1 {
                 // savepoints are not released in MSSQL
-                conn.execute("COMMIT TRAN").await?;
+                let query = end_transaction(conn, "COMMIT TRAN");
+
+                conn.execute(&*query).await?;
+                conn.restore_isolation = None;
             }
 
             Ok(())
@@ -42,21 +96,25 @@ impl TransactionManager for MssqlTransactionManager {
 
     fn rollback(conn: &mut MssqlConnection, depth: usize) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
-            let query = if depth == 1 {
-                Cow::Borrowed("ROLLBACK TRAN")
-            } else {
-                Cow::Owned(format!("ROLLBACK TRAN _sqlx_savepoint_{}", depth - 1))
-            };
+            if depth == 1 {
+                let query = end_transaction(conn, "ROLLBACK TRAN");
 
-            conn.execute(&*query).await?;
+                conn.execute(&*query).await?;
+                conn.restore_isolation = None;
+            } else {
+                conn.execute(&*format!("ROLLBACK TRAN _sqlx_savepoint_{}", depth - 1))
+                    .await?;
+            }
 
             Ok(())
         })
     }
 
+    // the outcome of the rollback is not observed here, so the isolation level is restored
+    // again when the next transaction ends
     fn start_rollback(conn: &mut MssqlConnection, depth: usize) {
         let query = if depth == 1 {
-            Cow::Borrowed("ROLLBACK TRAN")
+            end_transaction(conn, "ROLLBACK TRAN")
         } else {
             Cow::Owned(format!("ROLLBACK TRAN _sqlx_savepoint_{}", depth - 1))
         };
@@ -71,3 +129,29 @@ impl TransactionManager for MssqlTransactionManager {
         );
     }
 }
+
+// appends the reset of the isolation level to the statement ending the transaction if
+// `begin` changed it for the session
+fn end_transaction(conn: &MssqlConnection, statement: &'static str) -> Cow<'static, str> {
+    match conn.restore_isolation {
+        Some(isolation) => Cow::Owned(format!(
+            "{}; SET TRANSACTION ISOLATION LEVEL {}",
+            statement, isolation
+        )),
+
+        None => Cow::Borrowed(statement),
+    }
+}
+
+// <https://docs.microsoft.com/en-us/sql/relational-databases/system-dynamic-management-views/sys-dm-exec-sessions-transact-sql>
+fn isolation_level_sql(level: i16) -> &'static str {
+    match level {
+        1 => "READ UNCOMMITTED",
+        3 => "REPEATABLE READ",
+        4 => "SERIALIZABLE",
+        5 => "SNAPSHOT",
+
+        // 0 is unspecified, which behaves as the default of READ COMMITTED
+        _ => "READ COMMITTED",
+    }
+}
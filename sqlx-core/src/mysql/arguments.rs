@@ -1,8 +1,12 @@
 use std::ops::{Deref, DerefMut};
 
+use bytes::Buf;
+
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
+use crate::mysql::protocol::text::{ColumnFlags, ColumnType};
 use crate::mysql::{MySql, MySqlTypeInfo};
+use crate::type_info::TypeInfo;
 use crate::types::Type;
 
 /// Implementation of [`Arguments`] for MySQL.
@@ -35,6 +39,71 @@ impl<'q> Arguments<'q> for MySqlArguments {
             self.null_bitmap[index / 8] |= (1 << (index % 8)) as u8;
         }
     }
+
+    fn format_values(&self) -> Vec<String> {
+        let mut buf: &[u8] = &self.values;
+
+        self.types
+            .iter()
+            .enumerate()
+            .map(|(index, ty)| {
+                // NULL values are only marked in the bitmap and take no space in the buffer
+                if self.null_bitmap[index / 8] & (1 << (index % 8)) as u8 != 0 {
+                    return "NULL".to_owned();
+                }
+
+                format_value(ty, &mut buf)
+            })
+            .collect()
+    }
+}
+
+// renders the value at the start of `buf` and advances past it; all values are in the
+// binary protocol format
+fn format_value(ty: &MySqlTypeInfo, buf: &mut &[u8]) -> String {
+    let unsigned = ty.flags.contains(ColumnFlags::UNSIGNED);
+
+    match ty.r#type {
+        ColumnType::Tiny if unsigned => buf.get_u8().to_string(),
+        ColumnType::Tiny => buf.get_i8().to_string(),
+        ColumnType::Short | ColumnType::Year if unsigned => buf.get_u16_le().to_string(),
+        ColumnType::Short | ColumnType::Year => buf.get_i16_le().to_string(),
+        ColumnType::Long | ColumnType::Int24 if unsigned => buf.get_u32_le().to_string(),
+        ColumnType::Long | ColumnType::Int24 => buf.get_i32_le().to_string(),
+        ColumnType::LongLong if unsigned => buf.get_u64_le().to_string(),
+        ColumnType::LongLong => buf.get_i64_le().to_string(),
+        ColumnType::Float => buf.get_f32_le().to_string(),
+        ColumnType::Double => buf.get_f64_le().to_string(),
+
+        // dates and times are prefixed with their length in a single byte
+        ColumnType::Date | ColumnType::Time | ColumnType::Datetime | ColumnType::Timestamp => {
+            let len = buf.get_u8() as usize;
+            buf.advance(len);
+
+            format!("<{}>", ty.name())
+        }
+
+        // everything else is sent as a length-encoded string
+        _ => {
+            let len = match buf.get_u8() {
+                0xfc => u64::from(buf.get_u16_le()),
+                0xfd => buf.get_uint_le(3),
+                0xfe => buf.get_u64_le(),
+
+                v => u64::from(v),
+            };
+
+            let (value, rest) = buf.split_at(len as usize);
+            *buf = rest;
+
+            // the binary character set is used for blobs
+            if ty.char_set == 63 {
+                format!("<{} bytes of {}>", value.len(), ty.name())
+            } else {
+                format!("{:?}", String::from_utf8_lossy(value))
+            }
+        }
+    }
 }
 
 impl Deref for MySqlArguments {
@@ -50,3 +119,27 @@ impl DerefMut for MySqlArguments {
         &mut self.values
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MySqlArguments;
+    use crate::arguments::Arguments;
+
+    #[test]
+    fn it_formats_values() {
+        let mut arguments = MySqlArguments::default();
+
+        arguments.add(-10_i32);
+        arguments.add(None::<String>);
+        arguments.add(u64::MAX);
+        arguments.add("a".repeat(300));
+        arguments.add(2.5_f32);
+        arguments.add(vec![0_u8; 4]);
+
+        let values = arguments.format_values();
+
+        assert_eq!(values[..3], ["-10", "NULL", "18446744073709551615"]);
+        assert_eq!(values[3], format!("{:?}", "a".repeat(300)));
+        assert_eq!(values[4..], ["2.5", "<4 bytes of BLOB>"]);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::column::Column;
+use crate::describe;
+use crate::ext::ustr::UStr;
+use crate::mysql::{MySql, MySqlTypeInfo};
+
+#[derive(Debug, Clone)]
+pub struct MySqlColumn {
+    pub(crate) ordinal: usize,
+    pub(crate) name: Option<UStr>,
+    pub(crate) type_info: Option<MySqlTypeInfo>,
+}
+
+impl crate::column::private_column::Sealed for MySqlColumn {}
+
+impl Column for MySqlColumn {
+    type Database = MySql;
+
+    fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    // the server does not send a name for some columns, which are given an empty name here
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or_default()
+    }
+
+    fn type_info(&self) -> Option<&MySqlTypeInfo> {
+        self.type_info.as_ref()
+    }
+
+    fn from_describe(ordinal: usize, column: describe::Column<MySql>) -> Self {
+        MySqlColumn {
+            ordinal,
+            name: Some(column.name.into()),
+            type_info: column.type_info,
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use std::iter;
+use std::sync::Arc;
+
 use bytes::Bytes;
 
 use crate::common::StatementCache;
@@ -12,6 +15,33 @@ use bytes::buf::BufExt;
 
 impl MySqlConnection {
     pub(crate) async fn establish(options: &MySqlConnectOptions) -> Result<Self, Error> {
+        if options.other_hosts.is_empty() {
+            return Self::establish_host(options).await;
+        }
+
+        let hosts = iter::once((&options.host, options.port))
+            .chain(options.other_hosts.iter().map(|(host, port)| (host, *port)));
+
+        let mut error = None;
+
+        // fail over to the next host, in order
+        for (host, port) in hosts {
+            let options = options.clone().host(host).port(port);
+
+            match Self::establish_host(&options).await {
+                Ok(conn) => return Ok(conn),
+
+                Err(e) => {
+                    log::debug!("failed to connect to {}:{}: {}", host, port, e);
+                    error = Some(e);
+                }
+            }
+        }
+
+        Err(error.expect("BUG: no host to connect to"))
+    }
+
+    pub(super) async fn establish_host(options: &MySqlConnectOptions) -> Result<Self, Error> {
         let mut stream: MySqlStream = MySqlStream::connect(options).await?;
 
         // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_connection_phase.html
@@ -19,6 +49,8 @@ impl MySqlConnection {
 
         let handshake: Handshake = stream.recv_packet().await?.decode()?;
 
+        let connection_id = handshake.connection_id;
+
         let mut plugin = handshake.auth_plugin;
         let mut nonce = handshake.auth_plugin_data;
 
@@ -99,8 +131,14 @@ impl MySqlConnection {
         Ok(Self {
             stream,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            close_statement: None,
             scratch_row_columns: Default::default(),
             scratch_row_column_names: Default::default(),
+            log_settings: options.log_settings.clone(),
+            connection_id,
+            options: Arc::new(options.clone()),
+            #[cfg(feature = "tracing")]
+            database: options.database.clone(),
         })
     }
 }
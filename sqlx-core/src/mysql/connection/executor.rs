@@ -7,10 +7,13 @@ use futures_core::stream::BoxStream;
 use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
 
+use crate::common::with_timeout;
+use crate::connection::Connection;
 use crate::describe::{Column, Describe};
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::ext::ustr::UStr;
+use crate::logging::QueryLogger;
 use crate::mysql::connection::stream::Busy;
 use crate::mysql::io::MySqlBufExt;
 use crate::mysql::protocol::response::Status;
@@ -19,15 +22,17 @@ use crate::mysql::protocol::statement::{
 };
 use crate::mysql::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
 use crate::mysql::protocol::Packet;
-use crate::mysql::row::MySqlColumn;
 use crate::mysql::{
-    MySql, MySqlArguments, MySqlConnection, MySqlRow, MySqlTypeInfo, MySqlValueFormat,
+    MySql, MySqlArguments, MySqlColumn, MySqlConnectOptions, MySqlConnection, MySqlDone, MySqlRow,
+    MySqlTypeInfo, MySqlValueFormat,
 };
 
 impl MySqlConnection {
-    async fn prepare(&mut self, query: &str) -> Result<u32, Error> {
-        if let Some(statement) = self.cache_statement.get_mut(query) {
-            return Ok(*statement);
+    async fn get_or_prepare(&mut self, query: &str, persistent: bool) -> Result<u32, Error> {
+        if persistent {
+            if let Some(statement) = self.cache_statement.get_mut(query) {
+                return Ok(*statement);
+            }
         }
 
         // https://dev.mysql.com/doc/internals/en/com-stmt-prepare.html
@@ -60,6 +65,13 @@ impl MySqlConnection {
             self.stream.maybe_recv_eof().await?;
         }
 
+        if !persistent {
+            // the statement is closed before the next query is executed
+            self.close_statement = Some(ok.statement_id);
+
+            return Ok(ok.statement_id);
+        }
+
         // in case of the cache being full, close the least recently used statement
         if let Some(statement) = self.cache_statement.insert(query, ok.statement_id) {
             self.stream.send_packet(StmtClose { statement }).await?;
@@ -98,7 +110,11 @@ impl MySqlConnection {
 
             let type_info = MySqlTypeInfo::from_column(&def);
 
-            columns.push(MySqlColumn { name, type_info });
+            columns.push(MySqlColumn {
+                ordinal: i as usize,
+                name,
+                type_info,
+            });
         }
 
         self.stream.maybe_recv_eof().await?;
@@ -111,12 +127,20 @@ impl MySqlConnection {
         &'c mut self,
         query: &str,
         arguments: Option<MySqlArguments>,
-    ) -> Result<impl Stream<Item = Result<Either<u64, MySqlRow>, Error>> + 'c, Error> {
+        persistent: bool,
+    ) -> Result<impl Stream<Item = Result<Either<MySqlDone, MySqlRow>, Error>> + 'c, Error> {
         self.stream.wait_until_ready().await?;
+
+        // now that the results of the previous query have been read, we can close
+        // its statement if it was not cached
+        if let Some(statement) = self.close_statement.take() {
+            self.stream.send_packet(StmtClose { statement }).await?;
+        }
+
         self.stream.busy = Busy::Result;
 
         let format = if let Some(arguments) = arguments {
-            let statement = self.prepare(query).await?;
+            let statement = self.get_or_prepare(query, persistent).await?;
 
             // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
             self.stream
@@ -145,7 +169,10 @@ impl MySqlConnection {
                     // this indicates either a successful query with no rows at all or a failed query
                     let ok = packet.ok()?;
 
-                    r#yield!(Either::Left(ok.affected_rows));
+                    r#yield!(Either::Left(MySqlDone {
+                        rows_affected: ok.affected_rows,
+                        last_insert_id: ok.last_insert_id,
+                    }));
 
                     if ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                         // more result sets exist, continue to the next one
@@ -166,7 +193,10 @@ impl MySqlConnection {
 
                     if packet[0] == 0xfe && packet.len() < 9 {
                         let eof = packet.eof(self.stream.capabilities)?;
-                        r#yield!(Either::Left(0));
+                        r#yield!(Either::Left(MySqlDone {
+                            rows_affected: 0,
+                            last_insert_id: 0,
+                        }));
 
                         if eof.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                             // more result sets exist, continue to the next one
@@ -197,22 +227,43 @@ impl MySqlConnection {
     }
 }
 
+// stops the statement running on the connection `connection_id`, which then fails with
+// `ER_QUERY_INTERRUPTED`; the connection itself is left open
+async fn kill_query(options: &MySqlConnectOptions, connection_id: u32) -> Result<(), Error> {
+    let mut conn = MySqlConnection::establish_host(options).await?;
+
+    conn.execute(&*format!("KILL QUERY {}", connection_id))
+        .await?;
+
+    conn.close().await
+}
+
 impl<'c> Executor<'c> for &'c mut MySqlConnection {
     type Database = MySql;
 
     fn fetch_many<'e, 'q: 'e, E: 'q>(
         self,
         mut query: E,
-    ) -> BoxStream<'e, Result<Either<u64, MySqlRow>, Error>>
+    ) -> BoxStream<'e, Result<Either<MySqlDone, MySqlRow>, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
         let s = query.query();
         let arguments = query.take_arguments();
+        let persistent = query.persistent();
+        let timeout = query.timeout();
+        let logger = QueryLogger::new(s, arguments.as_ref(), &self.log_settings);
+
+        #[cfg(feature = "tracing")]
+        let logger = logger.span("mysql", self.database.as_deref());
 
-        Box::pin(try_stream! {
-            let s = self.run(s, arguments).await?;
+        // a query that times out is killed from a new connection
+        let options = Arc::clone(&self.options);
+        let connection_id = self.connection_id;
+
+        let s = try_stream! {
+            let s = self.run(s, arguments, persistent).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -220,7 +271,11 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
             }
 
             Ok(())
-        })
+        };
+
+        Box::pin(logger.instrument(with_timeout(s, timeout, async move {
+            kill_query(&options, connection_id).await
+        })))
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
@@ -7,13 +7,13 @@ use futures_util::FutureExt;
 use hashbrown::HashMap;
 
 use crate::common::StatementCache;
-use crate::connection::{Connect, Connection};
+use crate::connection::{Connect, Connection, LogSettings};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::ext::ustr::UStr;
 use crate::mysql::protocol::statement::StmtClose;
 use crate::mysql::protocol::text::{Ping, Quit};
-use crate::mysql::row::MySqlColumn;
+use crate::mysql::MySqlColumn;
 use crate::mysql::{MySql, MySqlConnectOptions};
 
 mod auth;
@@ -38,11 +38,27 @@ pub struct MySqlConnection {
     // cache by query string to the statement id
     cache_statement: StatementCache<u32>,
 
+    // a statement that was prepared for a single execution and not cached,
+    // to be closed once its results have been read
+    close_statement: Option<u32>,
+
     // working memory for the active row's column information
     // this allows us to re-use these allocations unless the user is persisting the
     // Row type past a stream iteration (clone-on-write)
     scratch_row_columns: Arc<Vec<MySqlColumn>>,
     scratch_row_column_names: Arc<HashMap<UStr, usize>>,
+
+    // how the statements run on this connection are logged
+    pub(crate) log_settings: LogSettings,
+
+    // the id of the connection on the server and the options of the host it is established
+    // with, to kill the running query from another connection when it times out
+    connection_id: u32,
+    options: Arc<MySqlConnectOptions>,
+
+    // the name of the database, reported in the spans of statements
+    #[cfg(feature = "tracing")]
+    pub(crate) database: Option<String>,
 }
 
 impl Debug for MySqlConnection {
@@ -73,6 +89,10 @@ impl Connection for MySqlConnection {
         })
     }
 
+    fn set_logging(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.stream.wait_until_ready().boxed()
     }
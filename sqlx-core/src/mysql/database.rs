@@ -1,7 +1,8 @@
 use crate::database::{Database, HasArguments, HasStatementCache, HasValueRef};
 use crate::mysql::value::{MySqlValue, MySqlValueRef};
 use crate::mysql::{
-    MySqlArguments, MySqlConnection, MySqlRow, MySqlTransactionManager, MySqlTypeInfo,
+    MySqlArguments, MySqlColumn, MySqlConnection, MySqlDone, MySqlRow, MySqlTransactionManager,
+    MySqlTypeInfo,
 };
 
 /// MySQL database driver.
@@ -15,6 +16,10 @@ impl Database for MySql {
 
     type Row = MySqlRow;
 
+    type Done = MySqlDone;
+
+    type Column = MySqlColumn;
+
     type TypeInfo = MySqlTypeInfo;
 
     type Value = MySqlValue;
@@ -0,0 +1,43 @@
+use std::iter::{Extend, IntoIterator};
+
+use crate::done::Done;
+use crate::mysql::MySql;
+
+/// The result of executing a query against MySQL.
+#[derive(Debug, Default)]
+pub struct MySqlDone {
+    pub(crate) rows_affected: u64,
+    pub(crate) last_insert_id: u64,
+}
+
+impl MySqlDone {
+    /// Returns the value generated for an `AUTO_INCREMENT` column by the last statement
+    /// that inserted a row, as reported by the server for this query.
+    ///
+    /// This is the same value as `LAST_INSERT_ID()` but without a second round-trip, which
+    /// could run on another connection in a pool. Returns `0` if no row was inserted.
+    pub fn last_insert_id(&self) -> u64 {
+        self.last_insert_id
+    }
+}
+
+impl Done for MySqlDone {
+    type Database = MySql;
+
+    fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+}
+
+impl Extend<MySqlDone> for MySqlDone {
+    fn extend<T: IntoIterator<Item = MySqlDone>>(&mut self, iter: T) {
+        for elem in iter {
+            self.rows_affected += elem.rows_affected;
+
+            // result sets do not report an insert ID; keep the last one that did
+            if elem.last_insert_id != 0 {
+                self.last_insert_id = elem.last_insert_id;
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, ErrorKind};
 use crate::mysql::protocol::response::ErrPacket;
 use smallvec::alloc::borrow::Cow;
 
@@ -62,6 +62,48 @@ impl DatabaseError for MySqlDatabaseError {
         self.code().map(Cow::Borrowed)
     }
 
+    fn kind(&self) -> ErrorKind {
+        // https://dev.mysql.com/doc/mysql-errors/8.0/en/server-error-reference.html
+        match self.number() {
+            // ER_DUP_ENTRY, ER_DUP_ENTRY_WITH_KEY_NAME
+            1062 | 1586 => ErrorKind::UniqueViolation,
+
+            // ER_NO_REFERENCED_ROW, ER_ROW_IS_REFERENCED and their `_2` variants
+            1216 | 1217 | 1451 | 1452 => ErrorKind::ForeignKeyViolation,
+
+            // ER_BAD_NULL_ERROR
+            1048 => ErrorKind::NotNullViolation,
+
+            // ER_CHECK_CONSTRAINT_VIOLATED
+            3819 => ErrorKind::CheckViolation,
+
+            // ER_LOCK_DEADLOCK
+            1213 => ErrorKind::Deadlock,
+
+            _ if self.code() == Some("40001") => ErrorKind::SerializationFailure,
+
+            _ => ErrorKind::Other,
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        matches!(
+            self.number(),
+            // ER_CON_COUNT_ERROR (too many connections)
+            1040
+                // ER_SERVER_SHUTDOWN
+                | 1053
+                // ER_NET_READ_ERROR, ER_NET_READ_INTERRUPTED, ER_NET_ERROR_ON_WRITE,
+                // ER_NET_WRITE_INTERRUPTED
+                | 1158
+                ..=1161
+                // ER_CONNECTION_KILLED
+                | 1927
+                // ER_SERVER_OFFLINE_MODE
+                | 3032
+        )
+    }
+
     #[doc(hidden)]
     fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
         self
@@ -1,8 +1,10 @@
 //! **MySQL** database driver.
 
 mod arguments;
+mod column;
 mod connection;
 mod database;
+mod done;
 mod error;
 mod io;
 mod options;
@@ -14,8 +16,10 @@ pub mod types;
 mod value;
 
 pub use arguments::MySqlArguments;
+pub use column::MySqlColumn;
 pub use connection::MySqlConnection;
 pub use database::MySql;
+pub use done::MySqlDone;
 pub use error::MySqlDatabaseError;
 pub use options::{MySqlConnectOptions, MySqlSslMode};
 pub use row::MySqlRow;
@@ -1,7 +1,12 @@
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
+
+use log::LevelFilter;
 use url::Url;
 
+use crate::common::split_hosts;
+use crate::connection::LogSettings;
 use crate::error::{BoxDynError, Error};
 
 /// Options for controlling the desired security state of the connection to the MySQL server.
@@ -65,9 +70,11 @@ impl FromStr for MySqlSslMode {
 /// The generic format of the connection URL:
 ///
 /// ```text
-/// mysql://[host][/database][?properties]
+/// mysql://[host][,...][/database][?properties]
 /// ```
 ///
+/// When several hosts are given, they are tried in order until a connection is established.
+///
 /// ## Properties
 ///
 /// |Parameter|Default|Description|
@@ -106,12 +113,15 @@ impl FromStr for MySqlSslMode {
 pub struct MySqlConnectOptions {
     pub(crate) host: String,
     pub(crate) port: u16,
+    // hosts to try after `host` and `port`
+    pub(crate) other_hosts: Vec<(String, u16)>,
     pub(crate) username: String,
     pub(crate) password: Option<String>,
     pub(crate) database: Option<String>,
     pub(crate) ssl_mode: MySqlSslMode,
     pub(crate) ssl_ca: Option<PathBuf>,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) log_settings: LogSettings,
 }
 
 impl Default for MySqlConnectOptions {
@@ -126,12 +136,14 @@ impl MySqlConnectOptions {
         Self {
             port: 3306,
             host: String::from("localhost"),
+            other_hosts: Vec::new(),
             username: String::from("root"),
             password: None,
             database: None,
             ssl_mode: MySqlSslMode::Preferred,
             ssl_ca: None,
             statement_cache_capacity: 100,
+            log_settings: LogSettings::new(),
         }
     }
 
@@ -152,6 +164,12 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Adds a host to try if connecting to the previous hosts fails.
+    pub fn add_host(mut self, host: &str, port: u16) -> Self {
+        self.other_hosts.push((host.to_owned(), port));
+        self
+    }
+
     /// Sets the username to connect as.
     pub fn username(mut self, username: &str) -> Self {
         self.username = username.to_owned();
@@ -213,15 +231,54 @@ impl MySqlConnectOptions {
         self.statement_cache_capacity = capacity;
         self
     }
+
+    /// Sets the level at which the statements run on the connection are logged.
+    ///
+    /// Statements are logged at `Debug` by default. See [`LogSettings`].
+    pub fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.log_settings = self.log_settings.log_statements(level);
+        self
+    }
+
+    /// Sets the level at which statements that ran for at least `duration` are logged.
+    ///
+    /// Statements that took a second or more are logged at `Warn` by default.
+    pub fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.log_settings = self.log_settings.log_slow_statements(level, duration);
+        self
+    }
+
+    /// Sets whether the values bound to a statement are logged along with it.
+    ///
+    /// Values are not logged by default. See [`LogSettings::log_arguments`].
+    pub fn log_arguments(mut self, log: bool) -> Self {
+        self.log_settings = self.log_settings.log_arguments(log);
+        self
+    }
+
+    /// Sets a function that returns the text to log in place of each bound value.
+    ///
+    /// See [`LogSettings::redact_arguments`].
+    pub fn redact_arguments<F>(mut self, redact: F) -> Self
+    where
+        F: Fn(usize, &str) -> String + 'static + Send + Sync,
+    {
+        self.log_settings = self.log_settings.redact_arguments(redact);
+        self
+    }
 }
 
 impl FromStr for MySqlConnectOptions {
     type Err = BoxDynError;
 
     fn from_str(s: &str) -> Result<Self, BoxDynError> {
+        let (s, hosts) = split_hosts(s)?;
         let url: Url = s.parse()?;
         let mut options = Self::new();
 
+        // hosts without a port use the default one
+        let default_port = options.port;
+
         if let Some(host) = url.host_str() {
             options = options.host(host);
         }
@@ -230,6 +287,10 @@ impl FromStr for MySqlConnectOptions {
             options = options.port(port);
         }
 
+        for (host, port) in hosts.iter().skip(1) {
+            options = options.add_host(host, port.unwrap_or(default_port));
+        }
+
         let username = url.username();
         if !username.is_empty() {
             options = options.username(username);
@@ -7,7 +7,7 @@ use crate::mysql::protocol::text::Query;
 use crate::mysql::{MySql, MySqlConnection};
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    TransactionManager, TransactionOptions,
 };
 
 /// Implementation of [`TransactionManager`] for MySQL.
@@ -16,9 +16,37 @@ pub struct MySqlTransactionManager;
 impl TransactionManager for MySqlTransactionManager {
     type Database = MySql;
 
-    fn begin(conn: &mut MySqlConnection, depth: usize) -> BoxFuture<'_, Result<(), Error>> {
+    fn begin(
+        conn: &mut MySqlConnection,
+        depth: usize,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
-            conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
+            if depth > 0 {
+                conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
+
+                return Ok(());
+            }
+
+            // without SESSION or GLOBAL, the isolation level only applies to the next transaction
+            if let Some(isolation) = options.isolation {
+                conn.execute(&*format!(
+                    "SET TRANSACTION ISOLATION LEVEL {}",
+                    isolation.as_sql()
+                ))
+                .await?;
+            }
+
+            match options.access_mode {
+                Some(access_mode) => {
+                    conn.execute(&*format!("START TRANSACTION {}", access_mode.as_sql()))
+                        .await?;
+                }
+
+                None => {
+                    conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
+                }
+            }
 
             Ok(())
         })
@@ -7,7 +7,8 @@ use futures_core::future::BoxFuture;
 use sqlx_rt::spawn;
 
 use super::inner::{DecrementSizeGuard, SharedPool};
-use crate::connection::Connection;
+use super::stats::{CloseReason, PoolEvent};
+use crate::connection::{Connection, LogSettings};
 use crate::database::Database;
 use crate::error::Error;
 
@@ -64,6 +65,11 @@ impl<DB: Database> Connection for PoolConnection<DB> {
     fn close(mut self) -> BoxFuture<'static, Result<(), Error>> {
         Box::pin(async move {
             let live = self.live.take().expect("PoolConnection double-dropped");
+
+            self.pool.emit(PoolEvent::Closed {
+                reason: CloseReason::Explicit,
+            });
+
             live.float(&self.pool).into_idle().close().await
         })
     }
@@ -73,6 +79,10 @@ impl<DB: Database> Connection for PoolConnection<DB> {
         Box::pin(self.deref_mut().ping())
     }
 
+    fn set_logging(&mut self, settings: LogSettings) {
+        self.get_mut().set_logging(settings)
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.get_mut().flush()
@@ -100,8 +110,21 @@ impl<DB: Database> Drop for PoolConnection<DB> {
         if let Some(mut live) = self.live.take() {
             let pool = self.pool.clone();
 
-            if live.raw.should_flush() {
+            if live.raw.should_flush()
+                || pool.options().after_release.is_some()
+                || pool.is_oversized()
+            {
                 spawn(async move {
+                    if pool.try_retire_surplus() {
+                        // the maximum size of the pool was lowered; retire this connection
+                        pool.emit(PoolEvent::Closed {
+                            reason: CloseReason::Surplus,
+                        });
+
+                        let _ = live.raw.close().await;
+                        return;
+                    }
+
                     // flush the connection (will immediately return if not needed) before
                     // we fully release to the pool
                     if let Err(e) = live.raw.flush().await {
@@ -109,11 +132,43 @@ impl<DB: Database> Drop for PoolConnection<DB> {
 
                         // we now consider the connection to be broken
                         // close the connection and drop from the pool
+                        pool.emit(PoolEvent::Closed {
+                            reason: CloseReason::Broken,
+                        });
+
                         let _ = live.float(&pool).into_idle().close().await;
-                    } else {
-                        // after we have flushed successfully, release to the pool
-                        pool.release(live.float(&pool));
+                        return;
                     }
+
+                    if let Some(test) = &pool.options().after_release {
+                        match test(&mut live.raw).await {
+                            Ok(true) => {}
+
+                            Ok(false) => {
+                                // the hook decided not to keep this connection
+                                pool.emit(PoolEvent::Closed {
+                                    reason: CloseReason::Hook,
+                                });
+
+                                let _ = live.float(&pool).into_idle().close().await;
+                                return;
+                            }
+
+                            Err(e) => {
+                                log::info!("after_release hook returned error: {}", e);
+
+                                pool.emit(PoolEvent::Closed {
+                                    reason: CloseReason::Hook,
+                                });
+
+                                let _ = live.float(&pool).into_idle().close().await;
+                                return;
+                            }
+                        }
+                    }
+
+                    // after we have flushed successfully, release to the pool
+                    pool.release(live.float(&pool));
                 });
             } else {
                 // nothing to flush, release immediately outside of a spawn
@@ -124,6 +179,10 @@ impl<DB: Database> Drop for PoolConnection<DB> {
 }
 
 impl<DB: Database> Live<DB> {
+    pub fn raw_mut(&mut self) -> &mut DB::Connection {
+        &mut self.raw
+    }
+
     pub fn float(self, pool: &SharedPool<DB>) -> Floating<'_, Self> {
         Floating {
             inner: self,
@@ -206,6 +265,12 @@ impl<'s, DB: Database> Floating<'s, Idle<DB>> {
         self.live.raw.ping().await
     }
 
+    /// Close a connection that was already removed from the size of the pool by
+    /// `SharedPool::try_retire_surplus`.
+    pub async fn close_retired(self) -> Result<(), Error> {
+        self.into_leakable().live.raw.close().await
+    }
+
     pub fn into_live(self) -> Floating<'s, Live<DB>> {
         Floating {
             inner: self.inner.live,
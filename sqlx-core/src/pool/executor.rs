@@ -18,7 +18,7 @@ where
     fn fetch_many<'e, 'q: 'e, E: 'q>(
         self,
         query: E,
-    ) -> BoxStream<'e, Result<Either<u64, DB::Row>, Error>>
+    ) -> BoxStream<'e, Result<Either<DB::Done, DB::Row>, Error>>
     where
         E: Execute<'q, Self::Database>,
     {
@@ -75,7 +75,10 @@ macro_rules! impl_executor_for_pool_connection {
                 query: E,
             ) -> futures_core::stream::BoxStream<
                 'e,
-                Result<either::Either<u64, $R>, crate::error::Error>,
+                Result<
+                    either::Either<<$DB as crate::database::Database>::Done, $R>,
+                    crate::error::Error,
+                >,
             >
             where
                 'c: 'e,
@@ -2,40 +2,134 @@ use std::cmp;
 use std::mem;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, Once};
+use std::time::{Duration, Instant};
 
-use crossbeam_queue::{ArrayQueue, SegQueue};
+use crossbeam_queue::SegQueue;
 use futures_core::task::{Poll, Waker};
 use futures_util::future;
 use sqlx_rt::{sleep, spawn, timeout};
 
-use crate::connection::Connect;
+use crate::connection::{Connect, Connection};
 use crate::database::Database;
 use crate::error::Error;
 use crate::pool::deadline_as_timeout;
 
 use super::connection::{Floating, Idle, Live};
+use super::stats::{CloseReason, PoolEvent, PoolMetrics, PoolStats};
 use super::Options;
 
+/// The wait before retrying a connection attempt that failed with a transient error; it
+/// doubles after each attempt up to `MAX_CONNECT_BACKOFF`.
+const MIN_CONNECT_BACKOFF: Duration = Duration::from_millis(10);
+const MAX_CONNECT_BACKOFF: Duration = Duration::from_secs(1);
+
 pub(crate) struct SharedPool<DB: Database> {
     connect_options: <DB::Connection as Connect>::Options,
-    idle_conns: ArrayQueue<Idle<DB>>,
+    idle_conns: SegQueue<Idle<DB>>,
     waiters: SegQueue<Waker>,
     pub(super) size: AtomicU32,
+    // start out as `options.max_size` and `options.min_size` but may be changed at runtime
+    max_size: AtomicU32,
+    min_size: AtomicU32,
     is_closed: AtomicBool,
-    options: Options,
+    // the reaper is spawned on the first acquire, as a lazy pool may be built outside of
+    // the async runtime
+    reaper: Once,
+    options: Options<DB>,
+    metrics: PoolMetrics,
 }
 
 impl<DB: Database> SharedPool<DB> {
-    pub fn options(&self) -> &Options {
+    pub fn options(&self) -> &Options<DB> {
         &self.options
     }
 
+    pub(super) fn stats(&self) -> PoolStats {
+        self.metrics.snapshot()
+    }
+
+    /// Record an event in the metrics of the pool and report it to the subscriber, if any.
+    pub(super) fn emit(&self, event: PoolEvent<'_>) {
+        self.metrics.record(&event);
+
+        if let Some(subscriber) = &self.options.event_subscriber {
+            subscriber.on_event(&event);
+        }
+    }
+
     pub(super) fn size(&self) -> u32 {
         self.size.load(Ordering::Acquire)
     }
 
+    pub(super) fn max_size(&self) -> u32 {
+        self.max_size.load(Ordering::Acquire)
+    }
+
+    pub(super) fn min_size(&self) -> u32 {
+        self.min_size.load(Ordering::Acquire)
+    }
+
+    pub(super) fn set_max_size(&self, max_size: u32) {
+        let old = self.max_size.swap(max_size, Ordering::AcqRel);
+
+        // the pool cannot keep more connections open than it may have
+        if self.min_size() > max_size {
+            self.min_size.store(max_size, Ordering::Release);
+        }
+
+        if max_size > old {
+            // tasks waiting in `acquire` may now open new connections
+            while let Ok(waker) = self.waiters.pop() {
+                waker.wake();
+            }
+        }
+    }
+
+    pub(super) fn set_min_size(self: &Arc<Self>, min_size: u32) {
+        let min_size = cmp::min(min_size, self.max_size());
+
+        self.min_size.store(min_size, Ordering::Release);
+
+        if self.size() < min_size {
+            // the reaper may not be running, or not for a while; open the connections now
+            let weak = Arc::downgrade(self);
+
+            spawn(async move {
+                if let Some(pool) = weak.upgrade() {
+                    pool.replenish().await;
+                }
+            });
+        }
+    }
+
+    /// Returns `true` if the pool has more connections than `max_size` allows, after
+    /// the maximum size was lowered.
+    pub(super) fn is_oversized(&self) -> bool {
+        self.size() > self.max_size()
+    }
+
+    /// Claim the retirement of a connection if the pool has more connections than `max_size`
+    /// allows.
+    ///
+    /// The size is decremented right away so connections released at the same time do not
+    /// all retire; the caller must close its connection without decrementing it again.
+    pub(super) fn try_retire_surplus(&self) -> bool {
+        let mut size = self.size();
+
+        while size > self.max_size() {
+            match self
+                .size
+                .compare_exchange(size, size - 1, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return true,
+                Err(new_size) => size = new_size,
+            }
+        }
+
+        false
+    }
+
     pub(super) fn num_idle(&self) -> usize {
         // NOTE: This is very expensive
         self.idle_conns.len()
@@ -47,7 +141,11 @@ impl<DB: Database> SharedPool<DB> {
 
     pub(super) async fn close(&self) {
         self.is_closed.store(true, Ordering::Release);
-        while self.idle_conns.pop().is_ok() {}
+        while self.idle_conns.pop().is_ok() {
+            self.emit(PoolEvent::Closed {
+                reason: CloseReason::PoolClosed,
+            });
+        }
         while let Ok(waker) = self.waiters.pop() {
             waker.wake();
         }
@@ -67,9 +165,7 @@ impl<DB: Database> SharedPool<DB> {
     }
 
     pub(super) fn release(&self, floating: Floating<'_, Live<DB>>) {
-        self.idle_conns
-            .push(floating.into_idle().into_leakable())
-            .expect("BUG: connection queue overflow in release()");
+        self.idle_conns.push(floating.into_idle().into_leakable());
 
         if let Ok(waker) = self.waiters.pop() {
             waker.wake();
@@ -82,7 +178,7 @@ impl<DB: Database> SharedPool<DB> {
     fn try_increment_size(&self) -> Option<DecrementSizeGuard<'_>> {
         let mut size = self.size();
 
-        while size < self.options.max_size {
+        while size < self.max_size() {
             let new_size = self.size.compare_and_swap(size, size + 1, Ordering::AcqRel);
 
             if new_size == size {
@@ -120,32 +216,65 @@ impl<DB: Database> SharedPool<DB> {
         .map_err(|_| Error::PoolTimedOut)
     }
 
-    pub(super) async fn new_arc(
+    /// Create the pool without opening any connection.
+    ///
+    /// Use [`init_min_connections`](#method.init_min_connections) to open the `min_size`
+    /// connections.
+    pub(super) fn new_arc(
         connect_options: <DB::Connection as Connect>::Options,
-        options: Options,
-    ) -> Result<Arc<Self>, Error> {
-        let mut pool = Self {
+        options: Options<DB>,
+    ) -> Arc<Self> {
+        let pool = Arc::new(Self {
             connect_options,
-            idle_conns: ArrayQueue::new(options.max_size as usize),
+            idle_conns: SegQueue::new(),
             waiters: SegQueue::new(),
             size: AtomicU32::new(0),
+            max_size: AtomicU32::new(options.max_size),
+            min_size: AtomicU32::new(options.min_size),
             is_closed: AtomicBool::new(false),
+            reaper: Once::new(),
             options,
-        };
-
-        pool.init_min_connections().await?;
+            metrics: PoolMetrics::default(),
+        });
 
-        let pool = Arc::new(pool);
-
-        spawn_reaper(&pool);
+        pool
+    }
 
-        Ok(pool)
+    /// Spawn the background task of the pool, if it was not spawned yet.
+    ///
+    /// This must be called from within the context of the async runtime.
+    pub(super) fn start_reaper(self: &Arc<Self>) {
+        self.reaper.call_once(|| spawn_reaper(self));
     }
 
     #[allow(clippy::needless_lifetimes)]
     pub(super) async fn acquire<'s>(&'s self) -> Result<Floating<'s, Live<DB>>, Error> {
         let start = Instant::now();
-        let deadline = start + self.options.connect_timeout;
+        let res = self
+            .acquire_until(start + self.options.acquire_timeout)
+            .await;
+
+        match res {
+            Ok(_) => self.emit(PoolEvent::Acquired {
+                wait: start.elapsed(),
+            }),
+
+            Err(Error::PoolTimedOut) => self.emit(PoolEvent::AcquireTimedOut {
+                wait: start.elapsed(),
+            }),
+
+            Err(_) => {}
+        }
+
+        res
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    async fn acquire_until<'s>(
+        &'s self,
+        deadline: Instant,
+    ) -> Result<Floating<'s, Live<DB>>, Error> {
+        let mut waited = false;
 
         // Unless the pool has been closed ...
         while !self.is_closed() {
@@ -153,19 +282,20 @@ impl<DB: Database> SharedPool<DB> {
             // if there is an idle connection in our channel.
             if let Ok(conn) = self.idle_conns.pop() {
                 let conn = Floating::from_idle(conn, self);
-                if let Some(live) = check_conn(conn, &self.options).await {
+                if let Some(live) = check_conn(conn, self).await {
                     return Ok(live);
                 }
             }
 
             if let Some(guard) = self.try_increment_size() {
                 // pool has slots available; open a new connection
-                match self.connect(deadline, guard).await {
-                    Ok(Some(conn)) => return Ok(conn),
-                    // [size] is internally decremented on _retry_ and _error_
-                    Ok(None) => continue,
-                    Err(e) => return Err(e),
-                }
+                // [size] is internally decremented on error
+                return self.connect(deadline, guard).await;
+            }
+
+            if !waited {
+                waited = true;
+                self.metrics.record_wait();
             }
 
             // Wait for a connection to become available (or we are allowed to open a new one)
@@ -176,84 +306,137 @@ impl<DB: Database> SharedPool<DB> {
         Err(Error::PoolClosed)
     }
 
-    // takes `&mut self` so this can only be called during init
-    async fn init_min_connections(&mut self) -> Result<(), Error> {
-        for _ in 0..self.options.min_size {
+    pub(super) async fn init_min_connections(&self) -> Result<(), Error> {
+        for _ in 0..self.min_size() {
             let deadline = Instant::now() + self.options.connect_timeout;
 
             // this guard will prevent us from exceeding `max_size`
             if let Some(guard) = self.try_increment_size() {
                 // [connect] will raise an error when past deadline
-                // [connect] returns None if its okay to retry
-                if let Some(conn) = self.connect(deadline, guard).await? {
-                    self.idle_conns
-                        .push(conn.into_idle().into_leakable())
-                        .expect("BUG: connection queue overflow in init_min_connections");
-                }
+                let conn = self.connect(deadline, guard).await?;
+                self.idle_conns.push(conn.into_idle().into_leakable());
             }
         }
 
         Ok(())
     }
 
+    /// Open a new connection, retrying transient errors with an exponential backoff
+    /// until `deadline`.
     async fn connect<'s>(
         &'s self,
         deadline: Instant,
         guard: DecrementSizeGuard<'s>,
-    ) -> Result<Option<Floating<'s, Live<DB>>>, Error> {
-        if self.is_closed() {
-            return Err(Error::PoolClosed);
-        }
+    ) -> Result<Floating<'s, Live<DB>>, Error> {
+        let mut backoff = MIN_CONNECT_BACKOFF;
+
+        loop {
+            if self.is_closed() {
+                return Err(Error::PoolClosed);
+            }
 
-        let timeout = super::deadline_as_timeout::<DB>(deadline)?;
+            // a single attempt may take at most `connect_timeout`
+            let timeout = cmp::min(
+                super::deadline_as_timeout::<DB>(deadline)?,
+                self.options.connect_timeout,
+            );
+
+            let start = Instant::now();
+
+            let connect = DB::Connection::connect_with(&self.connect_options);
+
+            // result here is `Result<Result<C, Error>, TimeoutError>`
+            let error = match sqlx_rt::timeout(timeout, connect).await {
+                // successfully established connection
+                Ok(Ok(mut raw)) => {
+                    if let Some(callback) = &self.options.after_connect {
+                        if let Err(e) = callback(&mut raw).await {
+                            // the hook failed; close the connection and bubble the error up
+                            self.emit(PoolEvent::ConnectFailed { error: &e });
+                            let _ = raw.close().await;
+                            return Err(e);
+                        }
+                    }
+
+                    self.emit(PoolEvent::Connected {
+                        duration: start.elapsed(),
+                    });
+
+                    return Ok(Floating::new_live(raw, guard));
+                }
 
-        // result here is `Result<Result<C, Error>, TimeoutError>`
-        match sqlx_rt::timeout(timeout, DB::Connection::connect_with(&self.connect_options)).await {
-            // successfully established connection
-            Ok(Ok(raw)) => Ok(Some(Floating::new_live(raw, guard))),
+                Ok(Err(e)) => e,
 
-            // an IO error while connecting is assumed to be the system starting up
-            Ok(Err(Error::Io(_))) => Ok(None),
+                // timed out
+                Err(_) => Error::PoolTimedOut,
+            };
 
-            // TODO: Handle other database "boot period"s
+            self.emit(PoolEvent::ConnectFailed { error: &error });
 
-            // [postgres] the database system is starting up
-            // TODO: Make this check actually check if this is postgres
-            Ok(Err(Error::Database(error))) if error.code().as_deref() == Some("57P03") => Ok(None),
+            match error {
+                // the attempt ran into `connect_timeout` but we may keep trying until `deadline`
+                Error::PoolTimedOut => {}
+
+                // the database is starting up, shutting down or overloaded, or the connection
+                // was refused or dropped, e.g. while the system is starting up
+                ref e if e.is_transient() => {}
+
+                // Any other error while connection should immediately
+                // terminate and bubble the error up
+                e => return Err(e),
+            }
 
-            // Any other error while connection should immediately
-            // terminate and bubble the error up
-            Ok(Err(e)) => Err(e),
+            // wait before trying again, but not past `deadline`
+            sleep(cmp::min(
+                backoff,
+                super::deadline_as_timeout::<DB>(deadline)?,
+            ))
+            .await;
 
-            // timed out
-            Err(_) => Err(Error::PoolTimedOut),
+            backoff = cmp::min(backoff * 2, MAX_CONNECT_BACKOFF);
         }
     }
 }
 
 // NOTE: Function names here are bizzare. Helpful help would be appreciated.
 
-fn is_beyond_lifetime<DB: Database>(live: &Live<DB>, options: &Options) -> bool {
+fn is_beyond_lifetime<DB: Database>(live: &Live<DB>, options: &Options<DB>) -> bool {
     // check if connection was within max lifetime (or not set)
     options
         .max_lifetime
         .map_or(false, |max| live.created.elapsed() > max)
 }
 
-fn is_beyond_idle<DB: Database>(idle: &Idle<DB>, options: &Options) -> bool {
+fn is_beyond_idle<DB: Database>(idle: &Idle<DB>, options: &Options<DB>) -> bool {
     // if connection wasn't idle too long (or not set)
     options
         .idle_timeout
         .map_or(false, |timeout| idle.since.elapsed() > timeout)
 }
 
-async fn check_conn<'s: 'p, 'p, DB: Database>(
+async fn check_conn<'s, DB: Database>(
     mut conn: Floating<'s, Idle<DB>>,
-    options: &'p Options,
+    pool: &'s SharedPool<DB>,
 ) -> Option<Floating<'s, Live<DB>>> {
+    let options = &pool.options;
+
+    // If the maximum size of the pool was lowered, retire the connection
+    if pool.try_retire_surplus() {
+        pool.emit(PoolEvent::Closed {
+            reason: CloseReason::Surplus,
+        });
+
+        let _ = conn.close_retired().await;
+        return None;
+    }
+
     // If the connection we pulled has expired, close the connection and
     // immediately create a new connection
     if is_beyond_lifetime(&conn, options) {
+        pool.emit(PoolEvent::Closed {
+            reason: CloseReason::MaxLifetime,
+        });
+
         // we're closing the connection either way
         // close the connection but don't really care about the result
         let _ = conn.close().await;
@@ -265,54 +448,154 @@ async fn check_conn<'s: 'p, 'p, DB: Database>(
             // either way we're fine to just discard the connection
             // the error itself here isn't necessarily unexpected so WARN is too strong
             log::info!("ping on idle connection returned error: {}", e);
+
+            pool.emit(PoolEvent::Closed {
+                reason: CloseReason::Broken,
+            });
+
             // connection is broken so don't try to close nicely
             return None;
         }
     }
 
+    if let Some(test) = &options.before_acquire {
+        match test(conn.raw_mut()).await {
+            Ok(true) => {}
+
+            Ok(false) => {
+                // the hook vetoed this connection
+                pool.emit(PoolEvent::Closed {
+                    reason: CloseReason::Hook,
+                });
+
+                let _ = conn.close().await;
+                return None;
+            }
+
+            Err(e) => {
+                log::info!("before_acquire hook returned error: {}", e);
+
+                pool.emit(PoolEvent::Closed {
+                    reason: CloseReason::Hook,
+                });
+
+                let _ = conn.close().await;
+                return None;
+            }
+        }
+    }
+
     // No need to re-connect; connection is alive or we don't care
     Some(conn.into_live())
 }
 
-/// if `max_lifetime` or `idle_timeout` is set, spawn a task that reaps senescent connections
+/// if `reaper_interval`, `max_lifetime` or `idle_timeout` is set, spawn a task that periodically
+/// reaps senescent connections, pings idle connections and opens connections up to `min_size`
 fn spawn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>) {
-    let period = match (pool.options.max_lifetime, pool.options.idle_timeout) {
-        (Some(it), None) | (None, Some(it)) => it,
+    let period = match pool.options.reaper_interval {
+        Some(period) => period,
+
+        None => match (pool.options.max_lifetime, pool.options.idle_timeout) {
+            (Some(it), None) | (None, Some(it)) => it,
 
-        (Some(a), Some(b)) => cmp::min(a, b),
+            (Some(a), Some(b)) => cmp::min(a, b),
 
-        (None, None) => return,
+            (None, None) => return,
+        },
     };
 
-    let pool = Arc::clone(&pool);
+    // hold a weak reference so the task does not keep a dropped pool alive
+    let weak = Arc::downgrade(pool);
 
     spawn(async move {
-        while !pool.is_closed.load(Ordering::Acquire) {
-            // reap at most the current size minus the minimum idle
-            let max_reaped = pool.size().saturating_sub(pool.options.min_size);
-
-            // collect connections to reap
-            let (reap, keep) = (0..max_reaped)
-                // only connections waiting in the queue
-                .filter_map(|_| pool.pop_idle())
-                .partition::<Vec<_>, _>(|conn| {
-                    is_beyond_idle(conn, &pool.options) || is_beyond_lifetime(conn, &pool.options)
+        while let Some(pool) = weak.upgrade() {
+            if pool.is_closed() {
+                break;
+            }
+
+            pool.reap().await;
+            pool.replenish().await;
+
+            drop(pool);
+
+            sleep(period).await;
+        }
+    });
+}
+
+impl<DB: Database> SharedPool<DB> {
+    /// Visit each idle connection once, closing the ones that expired or failed a ping.
+    async fn reap(&self) {
+        // reap idle connections down to the minimum size
+        let mut max_reaped = self.size().saturating_sub(self.min_size());
+
+        for _ in 0..self.idle_conns.len() {
+            let mut conn = match self.pop_idle() {
+                Some(conn) => conn,
+                None => break,
+            };
+
+            if self.try_retire_surplus() {
+                self.emit(PoolEvent::Closed {
+                    reason: CloseReason::Surplus,
                 });
 
-            for conn in keep {
-                // return these connections to the pool first
-                pool.idle_conns
-                    .push(conn.into_leakable())
-                    .expect("BUG: connection queue overflow in spawn_reaper");
+                let _ = conn.close_retired().await;
+                continue;
             }
 
-            for conn in reap {
-                let _ = conn.close().await;
+            let reason = if is_beyond_lifetime(&conn, &self.options) {
+                Some(CloseReason::MaxLifetime)
+            } else if max_reaped > 0 && is_beyond_idle(&conn, &self.options) {
+                max_reaped -= 1;
+                Some(CloseReason::IdleTimeout)
+            } else if let Err(e) = conn.ping().await {
+                log::info!("ping on idle connection returned error: {}", e);
+                Some(CloseReason::Broken)
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => {
+                    self.emit(PoolEvent::Closed { reason });
+
+                    let _ = conn.close().await;
+                }
+
+                None => {
+                    // return the connection to the back of the queue, keeping its idle time
+                    self.idle_conns.push(conn.into_leakable());
+
+                    if let Ok(waker) = self.waiters.pop() {
+                        waker.wake();
+                    }
+                }
             }
+        }
+    }
 
-            sleep(period).await;
+    /// Open new connections until the pool has at least `min_size` connections.
+    async fn replenish(&self) {
+        while self.size() < self.min_size() {
+            let guard = match self.try_increment_size() {
+                Some(guard) => guard,
+                None => return,
+            };
+
+            let deadline = Instant::now() + self.options.connect_timeout;
+
+            match self.connect(deadline, guard).await {
+                Ok(conn) => self.release(conn),
+
+                // the database may still be starting up; try again on the next run
+                Err(e) => {
+                    log::info!("failed to open a connection for min_size: {}", e);
+                    return;
+                }
+            }
         }
-    });
+    }
 }
 
 /// RAII guard returned by `Pool::try_increment_size()` and others.
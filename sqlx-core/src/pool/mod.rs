@@ -50,10 +50,13 @@ use std::future::Future;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use futures_core::future::BoxFuture;
+use sqlx_rt::sleep;
+
 use crate::connection::Connect;
 use crate::database::Database;
 use crate::error::Error;
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionManager, TransactionOptions};
 
 use self::inner::SharedPool;
 use self::options::Options;
@@ -64,9 +67,17 @@ mod executor;
 mod connection;
 mod inner;
 mod options;
+mod retry;
+mod routing;
+mod stats;
 
 pub use self::connection::PoolConnection;
 pub use self::options::Builder;
+pub use self::retry::RetryPolicy;
+pub use self::routing::{ReplicaHealth, ReplicaSelection, RoutingPool};
+pub use self::stats::{
+    CloseCounts, CloseReason, PoolEvent, PoolEventSubscriber, PoolStats, WaitTimeHistogram,
+};
 
 /// An alias for [`Transaction`] when returned from [`Pool::begin`].
 pub type PoolTransaction<DB, C = PoolConnection<DB>> = Transaction<'static, DB, C>;
@@ -90,6 +101,22 @@ impl<DB: Database> Pool<DB> {
         Self::builder().build_with(options).await
     }
 
+    /// Creates a new connection pool with the default pool configuration and the given connection
+    /// string, without connecting to the database.
+    ///
+    /// See [`Builder::build_lazy`].
+    pub fn connect_lazy(url: &str) -> Result<Self, Error> {
+        Self::builder().build_lazy(url)
+    }
+
+    /// Creates a new connection pool with the default pool configuration and the given connection
+    /// options, without connecting to the database.
+    ///
+    /// See [`Builder::build_lazy_with`].
+    pub fn connect_lazy_with(options: <DB::Connection as Connect>::Options) -> Self {
+        Self::builder().build_lazy_with(options)
+    }
+
     /// Returns a [`Builder`] to configure a new connection pool.
     pub fn builder() -> Builder<DB> {
         Builder::new()
@@ -97,10 +124,17 @@ impl<DB: Database> Pool<DB> {
 
     /// Retrieves a connection from the pool.
     ///
-    /// Waits for at most the configured connection timeout before returning an error.
+    /// Waits for at most the configured acquire timeout before returning an error.
     pub fn acquire(&self) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
         let shared = self.0.clone();
-        async move { shared.acquire().await.map(|conn| conn.attach(&shared)) }
+
+        instrument!(
+            async move {
+                shared.start_reaper();
+                shared.acquire().await.map(|conn| conn.attach(&shared))
+            },
+            "sqlx.pool.acquire"
+        )
     }
 
     /// Attempts to retrieve a connection from the pool if there is one available.
@@ -112,7 +146,91 @@ impl<DB: Database> Pool<DB> {
 
     /// Retrieves a new connection and immediately begins a new transaction.
     pub async fn begin(&self) -> Result<Transaction<'static, DB, PoolConnection<DB>>, Error> {
-        Ok(Transaction::begin(self.acquire().await?).await?)
+        self.begin_with(TransactionOptions::default()).await
+    }
+
+    /// Retrieves a new connection and immediately begins a new transaction with the given
+    /// isolation level, access mode and deferrability.
+    pub async fn begin_with(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<Transaction<'static, DB, PoolConnection<DB>>, Error> {
+        Transaction::begin(self.acquire().await?, options).await
+    }
+
+    /// Runs the function inside a transaction and commits it, running it again in a new
+    /// transaction if it fails with an error that is expected to succeed on a retry.
+    ///
+    /// An error is retried when the database reports a serialization failure or a deadlock,
+    /// either from the function or from the commit. These errors are recognized by the
+    /// SQLSTATE or error code of each database:
+    ///
+    ///  * PostgreSQL: `40001` (serialization failure) and `40P01` (deadlock detected).
+    ///  * MySQL: `40001` (as returned for error 1213, deadlock found).
+    ///  * SQLite: `SQLITE_BUSY`.
+    ///  * MSSQL: error 1205 (deadlock victim) and 3960 (snapshot update conflict).
+    ///
+    /// Any other error rolls back the transaction and is returned immediately. When the
+    /// attempts of `policy` are exhausted, the error of the last attempt is returned.
+    ///
+    /// ```rust,ignore
+    /// let total: i64 = pool
+    ///     .transaction_with_retry(RetryPolicy::new(), |tx| {
+    ///         Box::pin(async move {
+    ///             sqlx::query("UPDATE accounts SET balance = balance - 10 WHERE id = 1")
+    ///                 .execute(&mut *tx)
+    ///                 .await?;
+    ///
+    ///             sqlx::query_scalar("SELECT SUM(balance) FROM accounts")
+    ///                 .fetch_one(&mut *tx)
+    ///                 .await
+    ///         })
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn transaction_with_retry<T, F>(
+        &self,
+        policy: RetryPolicy,
+        mut f: F,
+    ) -> Result<T, Error>
+    where
+        T: Send,
+        F: for<'c> FnMut(&'c mut PoolTransaction<DB>) -> BoxFuture<'c, Result<T, Error>> + Send,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let mut tx = self.begin_with(policy.options).await?;
+
+            let error = match f(&mut tx).await {
+                Ok(value) => match tx.commit().await {
+                    Ok(()) => return Ok(value),
+                    Err(error) => error,
+                },
+
+                Err(error) => {
+                    // a failed rollback must not hide the error of the closure, which may be
+                    // retryable
+                    if let Err(rollback_error) = tx.rollback().await {
+                        log::warn!("failed to roll back a transaction: {}", rollback_error);
+                    }
+
+                    error
+                }
+            };
+
+            let retryable = matches!(
+                error.as_database_error(),
+                Some(error) if DB::TransactionManager::is_retryable(error)
+            );
+
+            if !retryable || attempt >= policy.max_attempts {
+                return Err(error);
+            }
+
+            sleep(policy.backoff(attempt)).await;
+            attempt += 1;
+        }
     }
 
     /// Attempts to retrieve a new connection and immediately begins a new transaction if there
@@ -121,7 +239,9 @@ impl<DB: Database> Pool<DB> {
         &self,
     ) -> Result<Option<Transaction<'static, DB, PoolConnection<DB>>>, Error> {
         match self.try_acquire() {
-            Some(conn) => Transaction::begin(conn).await.map(Some),
+            Some(conn) => Transaction::begin(conn, TransactionOptions::default())
+                .await
+                .map(Some),
             None => Ok(None),
         }
     }
@@ -149,19 +269,49 @@ impl<DB: Database> Pool<DB> {
         self.0.num_idle()
     }
 
-    /// Returns the configured maximum pool size.
+    /// Returns the maximum pool size.
     pub fn max_size(&self) -> u32 {
-        self.0.options().max_size
+        self.0.max_size()
     }
 
-    /// Returns the maximum time spent acquiring a new connection before an error is returned.
+    /// Change the maximum number of connections of this pool.
+    ///
+    /// Raising the maximum lets tasks waiting in [`acquire`](#method.acquire) open new
+    /// connections right away. When lowering it, connections in use are not interrupted;
+    /// surplus connections are closed as they are released to or taken from the pool. The
+    /// minimum size is lowered as well if it is above the new maximum.
+    pub fn set_max_size(&self, max_size: u32) {
+        self.0.set_max_size(max_size);
+    }
+
+    /// Returns the maximum time a single attempt to connect to the database may take.
     pub fn connect_timeout(&self) -> Duration {
         self.0.options().connect_timeout
     }
 
-    /// Returns the configured minimum idle connection count.
+    /// Returns the maximum time spent acquiring a connection before an error is returned.
+    pub fn acquire_timeout(&self) -> Duration {
+        self.0.options().acquire_timeout
+    }
+
+    /// Returns the minimum idle connection count.
     pub fn min_size(&self) -> u32 {
-        self.0.options().min_size
+        self.0.min_size()
+    }
+
+    /// Change the minimum number of connections of this pool.
+    ///
+    /// If the pool has fewer connections, new ones are opened right away in the background;
+    /// afterwards, the pool is kept at this size by the task described in
+    /// [`Builder::reaper_interval`]. The minimum is capped at the [maximum size](#method.max_size).
+    pub fn set_min_size(&self, min_size: u32) {
+        self.0.set_min_size(min_size);
+    }
+
+    /// Returns a snapshot of the counters of this pool: acquires, waits and their duration,
+    /// timeouts, connects, connect failures and closed connections.
+    pub fn stats(&self) -> PoolStats {
+        self.0.stats()
     }
 
     /// Returns the configured maximum connection lifetime.
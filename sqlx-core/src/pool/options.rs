@@ -1,5 +1,9 @@
+use std::fmt::{self, Debug, Formatter};
 use std::{marker::PhantomData, time::Duration};
 
+use futures_core::future::BoxFuture;
+
+use super::stats::PoolEventSubscriber;
 use super::Pool;
 use crate::connection::Connect;
 use crate::database::Database;
@@ -9,7 +13,7 @@ use crate::pool::inner::SharedPool;
 /// [`Pool`] factory, which can be used to configure the properties of a new connection pool.
 pub struct Builder<DB: Database> {
     phantom: PhantomData<DB>,
-    options: Options,
+    options: Options<DB>,
 }
 
 impl<DB: Database> Builder<DB> {
@@ -25,7 +29,9 @@ impl<DB: Database> Builder<DB> {
                 // don't open connections until necessary
                 min_size: 0,
                 // try to connect for 10 seconds before giving up
-                connect_timeout: Duration::from_secs(60),
+                connect_timeout: Duration::from_secs(10),
+                // wait for a connection for 30 seconds before giving up
+                acquire_timeout: Duration::from_secs(30),
                 // reap connections that have been alive > 30 minutes
                 // prevents unbounded live-leaking of memory due to naive prepared statement caching
                 // see src/cache.rs for context
@@ -34,6 +40,14 @@ impl<DB: Database> Builder<DB> {
                 idle_timeout: None,
                 // If true, test the health of a connection on acquire
                 test_on_acquire: true,
+                // run the reaper as often as `max_lifetime` and `idle_timeout` require
+                reaper_interval: None,
+                // no hooks unless requested
+                after_connect: None,
+                before_acquire: None,
+                after_release: None,
+                // no one is listening
+                event_subscriber: None,
             },
         }
     }
@@ -44,23 +58,41 @@ impl<DB: Database> Builder<DB> {
         self
     }
 
-    /// Set the amount of time to attempt connecting to the database.
+    /// Set the amount of time a single attempt to connect to the database may take.
+    ///
+    /// When the pool is built, an error is returned if a connection for [`min_size`] could
+    /// not be opened within this time. In [`Pool::acquire`], an attempt that runs into this
+    /// timeout is retried until the [`acquire_timeout`] elapses.
     ///
-    /// If this timeout elapses, [`Pool::acquire`] will return an error.
+    /// [`min_size`]: #method.min_size
+    /// [`acquire_timeout`]: #method.acquire_timeout
     pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
         self.options.connect_timeout = connect_timeout;
         self
     }
 
+    /// Set the maximum amount of time [`Pool::acquire`] waits for a connection, whether an
+    /// idle one or a newly opened one.
+    ///
+    /// If this timeout elapses, [`Pool::acquire`] will return [`Error::PoolTimedOut`].
+    ///
+    /// [`Error::PoolTimedOut`]: crate::error::Error::PoolTimedOut
+    pub fn acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.options.acquire_timeout = acquire_timeout;
+        self
+    }
+
     /// Set the minimum number of connections to maintain at all times.
     ///
     /// When the pool is built, this many connections will be automatically spun up.
     ///
     /// If any connection is reaped by [`max_lifetime`] or [`idle_timeout`] and it brings
-    /// the connection count below this amount, a new connection will be opened to replace it.
+    /// the connection count below this amount, a new connection will be opened to replace it
+    /// on the next run of the [`reaper_interval`].
     ///
     /// [`max_lifetime`]: #method.max_lifetime
     /// [`idle_timeout`]: #method.idle_timeout
+    /// [`reaper_interval`]: #method.reaper_interval
     pub fn min_size(mut self, min_size: u32) -> Self {
         self.options.min_size = min_size;
         self
@@ -95,6 +127,24 @@ impl<DB: Database> Builder<DB> {
         self
     }
 
+    /// Set how often the pool maintains its connections in the background.
+    ///
+    /// On each run, a task spawned on the async runtime closes idle connections that are past
+    /// [`max_lifetime`] or [`idle_timeout`], pings the remaining idle connections to discard
+    /// broken ones and opens new connections until the pool has [`min_size`] connections.
+    ///
+    /// When set to `None` (the default), the task runs at the shorter of [`max_lifetime`] and
+    /// [`idle_timeout`] and is not spawned if both of them are `None`. The task of a lazily built
+    /// pool is spawned when a connection is first acquired.
+    ///
+    /// [`max_lifetime`]: #method.max_lifetime
+    /// [`idle_timeout`]: #method.idle_timeout
+    /// [`min_size`]: #method.min_size
+    pub fn reaper_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+        self.options.reaper_interval = interval.into();
+        self
+    }
+
     /// If true, the health of a connection will be verified by a call to [`Connection::ping`]
     /// before returning the connection.
     ///
@@ -106,6 +156,88 @@ impl<DB: Database> Builder<DB> {
         self
     }
 
+    /// Perform an action after connecting to the database, before the connection is used.
+    ///
+    /// This runs for every new connection the pool opens, including those opened to satisfy
+    /// [`min_size`]. It is a good place to set session parameters:
+    ///
+    /// ```rust,ignore
+    /// let pool = PgPool::builder()
+    ///     .after_connect(|conn| {
+    ///         Box::pin(async move {
+    ///             conn.execute("SET application_name = 'my_app'; SET search_path = 'my_schema';")
+    ///                 .await?;
+    ///
+    ///             Ok(())
+    ///         })
+    ///     })
+    ///     .build("postgres:// …")
+    ///     .await?;
+    /// ```
+    ///
+    /// If the hook returns an error, the connection is closed and the error is returned
+    /// from the call that was opening it (e.g. [`Pool::acquire`]).
+    ///
+    /// [`min_size`]: #method.min_size
+    pub fn after_connect<F>(mut self, callback: F) -> Self
+    where
+        F: for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<(), Error>>
+            + 'static
+            + Send
+            + Sync,
+    {
+        self.options.after_connect = Some(Box::new(callback));
+        self
+    }
+
+    /// Perform an action on an idle connection before it is handed out by [`Pool::acquire`].
+    ///
+    /// Returning `Ok(true)` hands out the connection. Returning `Ok(false)` or an error
+    /// closes the connection and the pool tries another one (or opens a new one).
+    ///
+    /// This runs after the check enabled by [`test_on_acquire`]. It does not run for new
+    /// connections nor for [`Pool::try_acquire`], which cannot wait for the hook.
+    ///
+    /// [`test_on_acquire`]: #method.test_on_acquire
+    /// [`Pool::try_acquire`]: crate::pool::Pool::try_acquire
+    pub fn before_acquire<F>(mut self, callback: F) -> Self
+    where
+        F: for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<bool, Error>>
+            + 'static
+            + Send
+            + Sync,
+    {
+        self.options.before_acquire = Some(Box::new(callback));
+        self
+    }
+
+    /// Perform an action on a connection when it is returned to the pool.
+    ///
+    /// Returning `Ok(true)` keeps the connection in the pool. Returning `Ok(false)` or an
+    /// error closes the connection instead.
+    ///
+    /// As connections are returned to the pool on `drop`, the hook runs in a background task.
+    pub fn after_release<F>(mut self, callback: F) -> Self
+    where
+        F: for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<bool, Error>>
+            + 'static
+            + Send
+            + Sync,
+    {
+        self.options.after_release = Some(Box::new(callback));
+        self
+    }
+
+    /// Report every [`PoolEvent`] of the pool to `subscriber`, e.g. to export metrics.
+    ///
+    /// The counters of [`Pool::stats`] are maintained regardless of this setting.
+    ///
+    /// [`PoolEvent`]: crate::pool::PoolEvent
+    pub fn event_subscriber(mut self, subscriber: impl PoolEventSubscriber) -> Self {
+        self.options.event_subscriber = Some(Box::new(subscriber));
+        self
+    }
+
     /// Consumes the builder, returning a new, initialized connection pool with the given
     /// connection string.
     ///
@@ -114,13 +246,8 @@ impl<DB: Database> Builder<DB> {
     ///
     /// [`min_size`]: #method.min_size
     pub async fn build(self, url: &str) -> Result<Pool<DB>, Error> {
-        Ok(Pool(
-            SharedPool::<DB>::new_arc(
-                url.parse().map_err(Error::ParseConnectOptions)?,
-                self.options,
-            )
-            .await?,
-        ))
+        self.build_with(url.parse().map_err(Error::ParseConnectOptions)?)
+            .await
     }
 
     /// Consumes the builder, returning a new, initialized connection pool with the given connection
@@ -134,9 +261,35 @@ impl<DB: Database> Builder<DB> {
         self,
         options: <DB::Connection as Connect>::Options,
     ) -> Result<Pool<DB>, Error> {
-        Ok(Pool(
-            SharedPool::<DB>::new_arc(options, self.options).await?,
-        ))
+        let pool = SharedPool::<DB>::new_arc(options, self.options);
+
+        if let Err(error) = pool.init_min_connections().await {
+            pool.close().await;
+            return Err(error);
+        }
+
+        pool.start_reaper();
+
+        Ok(Pool(pool))
+    }
+
+    /// Consumes the builder, returning a new connection pool with the given connection string
+    /// without connecting to the database.
+    ///
+    /// Connections are opened on first use, so this only fails if the connection string
+    /// is invalid. The background task of the pool is also started on first use, so this may
+    /// be called outside of the async runtime.
+    pub fn build_lazy(self, url: &str) -> Result<Pool<DB>, Error> {
+        Ok(self.build_lazy_with(url.parse().map_err(Error::ParseConnectOptions)?))
+    }
+
+    /// Consumes the builder, returning a new connection pool with the given connection options
+    /// without connecting to the database.
+    ///
+    /// Connections are opened on first use. The background task of the pool is also started on
+    /// first use, so this may be called outside of the async runtime.
+    pub fn build_lazy_with(self, options: <DB::Connection as Connect>::Options) -> Pool<DB> {
+        Pool(SharedPool::<DB>::new_arc(options, self.options))
     }
 }
 
@@ -146,12 +299,54 @@ impl<DB: Database> Default for Builder<DB> {
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct Options {
+pub(crate) struct Options<DB: Database> {
     pub max_size: u32,
     pub connect_timeout: Duration,
+    pub acquire_timeout: Duration,
     pub min_size: u32,
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
     pub test_on_acquire: bool,
+    pub reaper_interval: Option<Duration>,
+    pub after_connect: Option<
+        Box<
+            dyn for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<(), Error>>
+                + 'static
+                + Send
+                + Sync,
+        >,
+    >,
+    pub before_acquire: Option<
+        Box<
+            dyn for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<bool, Error>>
+                + 'static
+                + Send
+                + Sync,
+        >,
+    >,
+    pub after_release: Option<
+        Box<
+            dyn for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<bool, Error>>
+                + 'static
+                + Send
+                + Sync,
+        >,
+    >,
+    pub event_subscriber: Option<Box<dyn PoolEventSubscriber>>,
+}
+
+impl<DB: Database> Debug for Options<DB> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // the hooks are opaque closures; only show the plain options
+        f.debug_struct("Options")
+            .field("max_size", &self.max_size)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("acquire_timeout", &self.acquire_timeout)
+            .field("min_size", &self.min_size)
+            .field("max_lifetime", &self.max_lifetime)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("test_on_acquire", &self.test_on_acquire)
+            .field("reaper_interval", &self.reaper_interval)
+            .finish()
+    }
 }
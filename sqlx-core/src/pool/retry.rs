@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use crate::transaction::TransactionOptions;
+
+/// Controls how [`Pool::transaction_with_retry`] re-runs a transaction that failed with a
+/// serialization failure or a deadlock.
+///
+/// Between attempts, the runner waits for a backoff that starts at
+/// [`initial_backoff`](#method.initial_backoff) and doubles after each attempt up to
+/// [`max_backoff`](#method.max_backoff).
+///
+/// ```rust,ignore
+/// let policy = RetryPolicy::new()
+///     .max_attempts(10)
+///     .initial_backoff(Duration::from_millis(5))
+///     .transaction_options(TransactionOptions {
+///         isolation: Some(IsolationLevel::Serializable),
+///         ..Default::default()
+///     });
+/// ```
+///
+/// [`Pool::transaction_with_retry`]: crate::pool::Pool::transaction_with_retry
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) options: TransactionOptions,
+}
+
+impl RetryPolicy {
+    /// Get a new policy with default options.
+    ///
+    /// See the source of this method for current defaults.
+    pub fn new() -> Self {
+        Self {
+            // run the transaction at most 5 times before giving up
+            max_attempts: 5,
+            // wait 10ms before the first retry
+            initial_backoff: Duration::from_millis(10),
+            // and never more than a second between attempts
+            max_backoff: Duration::from_secs(1),
+            // begin with the defaults of the database
+            options: TransactionOptions::default(),
+        }
+    }
+
+    /// Set the maximum number of times the transaction is run, including the first attempt.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the amount of time to wait before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the maximum amount of time to wait between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the options that each attempt begins its transaction with.
+    pub fn transaction_options(mut self, options: TransactionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        // 1 => initial, 2 => initial * 2, 3 => initial * 4, ...
+        let factor = 1_u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+
+        self.initial_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn it_doubles_the_backoff_up_to_the_max() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(10))
+            .max_backoff(Duration::from_millis(50));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(10));
+        assert_eq!(policy.backoff(2), Duration::from_millis(20));
+        assert_eq!(policy.backoff(3), Duration::from_millis(40));
+        assert_eq!(policy.backoff(4), Duration::from_millis(50));
+        assert_eq!(policy.backoff(100), Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,331 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use either::Either;
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+
+use crate::database::Database;
+use crate::describe::Describe;
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::transaction::{Transaction, TransactionOptions};
+
+use super::{Pool, PoolConnection, PoolStats};
+
+/// How a [`RoutingPool`] picks the replica that runs a read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReplicaSelection {
+    /// Cycle through the healthy replicas.
+    RoundRobin,
+
+    /// Use the healthy replica with the fewest connections in use.
+    LeastBusy,
+}
+
+/// The health of a replica of a [`RoutingPool`], as returned by
+/// [`RoutingPool::replica_health`].
+#[derive(Debug, Clone)]
+pub struct ReplicaHealth {
+    /// Whether reads are currently routed to this replica.
+    pub healthy: bool,
+
+    /// The counters of the pool of this replica.
+    pub stats: PoolStats,
+}
+
+/// A connection pool that splits reads and writes over a primary and its replicas.
+///
+/// `&RoutingPool` implements [`Executor`]. Queries run with [`fetch`], [`fetch_all`],
+/// [`fetch_one`], [`fetch_optional`] and [`fetch_many`] go to a healthy replica, while
+/// [`execute`] and [`execute_many`], transactions and prepared statements go to the primary.
+/// When no replica is healthy, reads go to the primary as well.
+///
+/// A query that writes and returns rows, such as `INSERT .. RETURNING`, must be run against
+/// [`primary`](#method.primary) explicitly.
+///
+/// A replica is considered unhealthy for [`unhealthy_for`](#method.unhealthy_for) after its
+/// pool reports a new connect failure, acquire timeout or broken connection in
+/// [`Pool::stats`], and for as long as its pool is closed.
+///
+/// ```rust,ignore
+/// let pool = RoutingPool::new(
+///     PgPool::new("postgres://primary/app").await?,
+///     vec![
+///         PgPool::new("postgres://replica-1/app").await?,
+///         PgPool::new("postgres://replica-2/app").await?,
+///     ],
+/// )
+/// .selection(ReplicaSelection::LeastBusy);
+///
+/// let users: Vec<User> = sqlx::query_as("SELECT * FROM users").fetch_all(&pool).await?;
+/// ```
+///
+/// [`fetch`]: crate::executor::Executor::fetch
+/// [`fetch_all`]: crate::executor::Executor::fetch_all
+/// [`fetch_one`]: crate::executor::Executor::fetch_one
+/// [`fetch_optional`]: crate::executor::Executor::fetch_optional
+/// [`fetch_many`]: crate::executor::Executor::fetch_many
+/// [`execute`]: crate::executor::Executor::execute
+/// [`execute_many`]: crate::executor::Executor::execute_many
+pub struct RoutingPool<DB: Database> {
+    primary: Pool<DB>,
+    replicas: Arc<[Replica<DB>]>,
+    selection: ReplicaSelection,
+    unhealthy_for: Duration,
+    next: Arc<AtomicUsize>,
+}
+
+struct Replica<DB: Database> {
+    pool: Pool<DB>,
+    state: Mutex<ReplicaState>,
+}
+
+struct ReplicaState {
+    // connect failures, timeouts and broken connections seen at the last health check
+    failures: u64,
+    unhealthy_until: Option<Instant>,
+}
+
+fn failures(stats: &PoolStats) -> u64 {
+    stats.connect_failures + stats.timeouts + stats.closes.broken
+}
+
+impl<DB: Database> Replica<DB> {
+    fn new(pool: Pool<DB>) -> Self {
+        Self {
+            state: Mutex::new(ReplicaState {
+                failures: failures(&pool.stats()),
+                unhealthy_until: None,
+            }),
+            pool,
+        }
+    }
+
+    fn health(&self, unhealthy_for: Duration) -> ReplicaHealth {
+        let stats = self.pool.stats();
+        let failures = failures(&stats);
+        let now = Instant::now();
+
+        let mut state = self.state.lock().unwrap();
+
+        if failures > state.failures {
+            // the pool ran into new failures since the last check
+            state.failures = failures;
+            state.unhealthy_until = Some(now + unhealthy_for);
+        }
+
+        let recovered = match state.unhealthy_until {
+            Some(until) => until <= now,
+            None => true,
+        };
+
+        ReplicaHealth {
+            healthy: recovered && !self.pool.is_closed(),
+            stats,
+        }
+    }
+
+    fn in_use(&self) -> usize {
+        (self.pool.size() as usize).saturating_sub(self.pool.idle())
+    }
+}
+
+impl<DB: Database> RoutingPool<DB> {
+    /// Route writes to `primary` and reads to `replicas`.
+    ///
+    /// See the source of this method for the default options.
+    pub fn new(primary: Pool<DB>, replicas: Vec<Pool<DB>>) -> Self {
+        Self {
+            primary,
+            replicas: replicas.into_iter().map(Replica::new).collect(),
+            // spread reads evenly over the replicas
+            selection: ReplicaSelection::RoundRobin,
+            // stop reading from a failing replica for 5 seconds
+            unhealthy_for: Duration::from_secs(5),
+            next: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Set how the replica that runs a read is picked.
+    pub fn selection(mut self, selection: ReplicaSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Set for how long a replica is not used after its pool reported a failure.
+    pub fn unhealthy_for(mut self, duration: Duration) -> Self {
+        self.unhealthy_for = duration;
+        self
+    }
+
+    /// Returns the pool of the primary.
+    pub fn primary(&self) -> &Pool<DB> {
+        &self.primary
+    }
+
+    /// Returns the pools of the replicas.
+    pub fn replicas(&self) -> impl Iterator<Item = &Pool<DB>> + '_ {
+        self.replicas.iter().map(|replica| &replica.pool)
+    }
+
+    /// Returns the health of each replica, in the order they were given to
+    /// [`new`](#method.new).
+    pub fn replica_health(&self) -> Vec<ReplicaHealth> {
+        self.replicas
+            .iter()
+            .map(|replica| replica.health(self.unhealthy_for))
+            .collect()
+    }
+
+    /// Returns the pool a read should run on: a healthy replica if there is one, or else
+    /// the primary.
+    pub fn reader(&self) -> &Pool<DB> {
+        let healthy = self
+            .replicas
+            .iter()
+            .filter(|replica| replica.health(self.unhealthy_for).healthy);
+
+        let replica = match self.selection {
+            ReplicaSelection::RoundRobin => {
+                let healthy: Vec<_> = healthy.collect();
+
+                if healthy.is_empty() {
+                    None
+                } else {
+                    let next = self.next.fetch_add(1, Ordering::Relaxed);
+                    Some(healthy[next % healthy.len()])
+                }
+            }
+
+            ReplicaSelection::LeastBusy => healthy.min_by_key(|replica| replica.in_use()),
+        };
+
+        match replica {
+            Some(replica) => &replica.pool,
+            None => &self.primary,
+        }
+    }
+
+    /// Retrieves a connection from the primary and immediately begins a new transaction.
+    pub async fn begin(&self) -> Result<Transaction<'static, DB, PoolConnection<DB>>, Error> {
+        self.primary.begin().await
+    }
+
+    /// Retrieves a connection from the primary and immediately begins a new transaction with
+    /// the given isolation level, access mode and deferrability.
+    pub async fn begin_with(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<Transaction<'static, DB, PoolConnection<DB>>, Error> {
+        self.primary.begin_with(options).await
+    }
+
+    /// Closes the pools of the primary and of every replica.
+    pub async fn close(&self) {
+        self.primary.close().await;
+
+        for replica in self.replicas.iter() {
+            replica.pool.close().await;
+        }
+    }
+}
+
+/// Returns a new [`RoutingPool`] tied to the same primary and replica pools.
+impl<DB: Database> Clone for RoutingPool<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            primary: self.primary.clone(),
+            replicas: Arc::clone(&self.replicas),
+            selection: self.selection,
+            unhealthy_for: self.unhealthy_for,
+            next: Arc::clone(&self.next),
+        }
+    }
+}
+
+impl<DB: Database> Debug for RoutingPool<DB> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RoutingPool")
+            .field("primary", &self.primary)
+            .field("replicas", &self.replicas().collect::<Vec<_>>())
+            .field("selection", &self.selection)
+            .field("unhealthy_for", &self.unhealthy_for)
+            .finish()
+    }
+}
+
+impl<'p, DB: Database> Executor<'p> for &'_ RoutingPool<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    type Database = DB;
+
+    fn execute<'e, 'q: 'e, E: 'q>(self, query: E) -> BoxFuture<'e, Result<DB::Done, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.primary.execute(query)
+    }
+
+    fn execute_many<'e, 'q: 'e, E: 'q>(self, query: E) -> BoxStream<'e, Result<DB::Done, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.primary.execute_many(query)
+    }
+
+    fn fetch<'e, 'q: 'e, E: 'q>(self, query: E) -> BoxStream<'e, Result<DB::Row, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.reader().fetch(query)
+    }
+
+    fn fetch_many<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<DB::Done, DB::Row>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.reader().fetch_many(query)
+    }
+
+    fn fetch_all<'e, 'q: 'e, E: 'q>(self, query: E) -> BoxFuture<'e, Result<Vec<DB::Row>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.reader().fetch_all(query)
+    }
+
+    fn fetch_one<'e, 'q: 'e, E: 'q>(self, query: E) -> BoxFuture<'e, Result<DB::Row, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.reader().fetch_one(query)
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Option<DB::Row>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.reader().fetch_optional(query)
+    }
+
+    #[doc(hidden)]
+    fn describe<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Describe<Self::Database>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        self.primary.describe(query)
+    }
+}
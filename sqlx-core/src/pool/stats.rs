@@ -0,0 +1,329 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// The upper bounds of the buckets of [`WaitTimeHistogram`]; the last bucket is unbounded.
+const WAIT_TIME_BUCKETS: [Duration; 10] = [
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(25),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+];
+
+/// Something that happened in a [`Pool`](crate::pool::Pool), as reported to a
+/// [`PoolEventSubscriber`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PoolEvent<'a> {
+    /// A connection was handed out by [`Pool::acquire`](crate::pool::Pool::acquire).
+    Acquired {
+        /// How long the caller waited for the connection.
+        wait: Duration,
+    },
+
+    /// [`Pool::acquire`](crate::pool::Pool::acquire) gave up waiting for a connection and
+    /// returned [`Error::PoolTimedOut`].
+    AcquireTimedOut {
+        /// How long the caller waited before timing out.
+        wait: Duration,
+    },
+
+    /// A new connection to the database was opened.
+    Connected {
+        /// How long it took to establish the connection.
+        duration: Duration,
+    },
+
+    /// An attempt to open a new connection failed.
+    ///
+    /// The pool keeps retrying [transient](crate::error::Error::is_transient) errors with a
+    /// backoff until the connect timeout elapses; each failed attempt is reported.
+    ConnectFailed { error: &'a Error },
+
+    /// A connection was closed and removed from the pool.
+    Closed { reason: CloseReason },
+}
+
+/// Why a connection was removed from a [`Pool`](crate::pool::Pool).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CloseReason {
+    /// The connection outlived the configured `max_lifetime`.
+    MaxLifetime,
+
+    /// The connection stayed idle for longer than the configured `idle_timeout`.
+    IdleTimeout,
+
+    /// The connection failed a health check or could not be flushed.
+    Broken,
+
+    /// The connection was rejected by a `before_acquire` or `after_release` hook.
+    Hook,
+
+    /// The connection was closed explicitly with
+    /// [`Connection::close`](crate::connection::Connection::close).
+    Explicit,
+
+    /// The pool was closed.
+    PoolClosed,
+
+    /// The pool had more connections than allowed after its maximum size was lowered with
+    /// [`Pool::set_max_size`](crate::pool::Pool::set_max_size).
+    Surplus,
+}
+
+/// Receives every [`PoolEvent`] of a [`Pool`](crate::pool::Pool).
+///
+/// Register a subscriber with
+/// [`Builder::event_subscriber`](crate::pool::Builder::event_subscriber). Events are reported
+/// synchronously from the tasks using the pool, so implementations should be fast and must
+/// not block; updating a set of metrics is the intended use.
+///
+/// This is implemented for closures that take a `&PoolEvent`:
+///
+/// ```rust,ignore
+/// let pool = PgPool::builder()
+///     .event_subscriber(|event: &PoolEvent<'_>| match event {
+///         PoolEvent::Acquired { wait } => ACQUIRE_WAIT.observe(wait.as_secs_f64()),
+///         PoolEvent::AcquireTimedOut { .. } => ACQUIRE_TIMEOUTS.inc(),
+///         _ => {}
+///     })
+///     .build("postgres:// …")
+///     .await?;
+/// ```
+pub trait PoolEventSubscriber: 'static + Send + Sync {
+    fn on_event(&self, event: &PoolEvent<'_>);
+}
+
+impl<F> PoolEventSubscriber for F
+where
+    F: Fn(&PoolEvent<'_>) + 'static + Send + Sync,
+{
+    fn on_event(&self, event: &PoolEvent<'_>) {
+        self(event)
+    }
+}
+
+/// A snapshot of the counters of a [`Pool`](crate::pool::Pool), returned by
+/// [`Pool::stats`](crate::pool::Pool::stats).
+///
+/// All counters start at zero when the pool is created and only ever increase.
+#[derive(Debug, Clone)]
+pub struct PoolStats {
+    /// The number of connections handed out by `acquire`.
+    pub acquires: u64,
+
+    /// The number of calls to `acquire` that had to wait for a connection to be released
+    /// or opened.
+    pub waits: u64,
+
+    /// The time spent waiting in `acquire`, for successful calls.
+    pub wait_time: WaitTimeHistogram,
+
+    /// The number of calls to `acquire` that failed with `PoolTimedOut`.
+    pub timeouts: u64,
+
+    /// The number of connections opened.
+    pub connects: u64,
+
+    /// The number of failed attempts to open a connection.
+    pub connect_failures: u64,
+
+    /// The number of connections closed, by reason.
+    pub closes: CloseCounts,
+}
+
+/// The number of connections closed by a [`Pool`](crate::pool::Pool), by [`CloseReason`].
+#[derive(Debug, Clone, Default)]
+pub struct CloseCounts {
+    pub max_lifetime: u64,
+    pub idle_timeout: u64,
+    pub broken: u64,
+    pub hook: u64,
+    pub explicit: u64,
+    pub pool_closed: u64,
+    pub surplus: u64,
+}
+
+/// A histogram of the time spent waiting for a connection.
+///
+/// Buckets are not cumulative; each observation is counted in the first bucket whose upper
+/// bound it does not exceed.
+#[derive(Debug, Clone)]
+pub struct WaitTimeHistogram {
+    buckets: Vec<(Option<Duration>, u64)>,
+    sum: Duration,
+}
+
+impl WaitTimeHistogram {
+    /// The buckets of the histogram as pairs of an inclusive upper bound and the number of
+    /// observations in the bucket. The last bucket has no upper bound.
+    pub fn buckets(&self) -> &[(Option<Duration>, u64)] {
+        &self.buckets
+    }
+
+    /// The total number of observations.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().map(|(_, count)| count).sum()
+    }
+
+    /// The sum of all observations.
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+}
+
+/// The live counters behind [`PoolStats`].
+#[derive(Default)]
+pub(crate) struct PoolMetrics {
+    acquires: AtomicU64,
+    waits: AtomicU64,
+    wait_time_buckets: [AtomicU64; WAIT_TIME_BUCKETS.len() + 1],
+    wait_time_sum_nanos: AtomicU64,
+    timeouts: AtomicU64,
+    connects: AtomicU64,
+    connect_failures: AtomicU64,
+    closed_max_lifetime: AtomicU64,
+    closed_idle_timeout: AtomicU64,
+    closed_broken: AtomicU64,
+    closed_hook: AtomicU64,
+    closed_explicit: AtomicU64,
+    closed_pool_closed: AtomicU64,
+    closed_surplus: AtomicU64,
+}
+
+impl PoolMetrics {
+    pub(crate) fn record(&self, event: &PoolEvent<'_>) {
+        match *event {
+            PoolEvent::Acquired { wait } => {
+                inc(&self.acquires);
+
+                let bucket = WAIT_TIME_BUCKETS
+                    .iter()
+                    .position(|bound| wait <= *bound)
+                    .unwrap_or(WAIT_TIME_BUCKETS.len());
+
+                inc(&self.wait_time_buckets[bucket]);
+
+                self.wait_time_sum_nanos
+                    .fetch_add(wait.as_nanos() as u64, Ordering::Relaxed);
+            }
+
+            PoolEvent::AcquireTimedOut { .. } => inc(&self.timeouts),
+
+            PoolEvent::Connected { .. } => inc(&self.connects),
+
+            PoolEvent::ConnectFailed { .. } => inc(&self.connect_failures),
+
+            PoolEvent::Closed { reason } => inc(match reason {
+                CloseReason::MaxLifetime => &self.closed_max_lifetime,
+                CloseReason::IdleTimeout => &self.closed_idle_timeout,
+                CloseReason::Broken => &self.closed_broken,
+                CloseReason::Hook => &self.closed_hook,
+                CloseReason::Explicit => &self.closed_explicit,
+                CloseReason::PoolClosed => &self.closed_pool_closed,
+                CloseReason::Surplus => &self.closed_surplus,
+            }),
+        }
+    }
+
+    /// Count an acquire that could not be served immediately.
+    pub(crate) fn record_wait(&self) {
+        inc(&self.waits);
+    }
+
+    pub(crate) fn snapshot(&self) -> PoolStats {
+        let buckets = WAIT_TIME_BUCKETS
+            .iter()
+            .copied()
+            .map(Some)
+            .chain(Some(None))
+            .zip(self.wait_time_buckets.iter().map(load))
+            .collect();
+
+        PoolStats {
+            acquires: load(&self.acquires),
+            waits: load(&self.waits),
+            wait_time: WaitTimeHistogram {
+                buckets,
+                sum: Duration::from_nanos(load(&self.wait_time_sum_nanos)),
+            },
+            timeouts: load(&self.timeouts),
+            connects: load(&self.connects),
+            connect_failures: load(&self.connect_failures),
+            closes: CloseCounts {
+                max_lifetime: load(&self.closed_max_lifetime),
+                idle_timeout: load(&self.closed_idle_timeout),
+                broken: load(&self.closed_broken),
+                hook: load(&self.closed_hook),
+                explicit: load(&self.closed_explicit),
+                pool_closed: load(&self.closed_pool_closed),
+                surplus: load(&self.closed_surplus),
+            },
+        }
+    }
+}
+
+#[inline]
+fn inc(counter: &AtomicU64) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+#[inline]
+fn load(counter: &AtomicU64) -> u64 {
+    counter.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CloseReason, PoolEvent, PoolMetrics};
+    use std::time::Duration;
+
+    #[test]
+    fn it_records_events() {
+        let metrics = PoolMetrics::default();
+
+        metrics.record(&PoolEvent::Acquired {
+            wait: Duration::from_secs(0),
+        });
+
+        metrics.record_wait();
+        metrics.record(&PoolEvent::Acquired {
+            wait: Duration::from_millis(20),
+        });
+
+        metrics.record_wait();
+        metrics.record(&PoolEvent::Acquired {
+            wait: Duration::from_secs(10),
+        });
+
+        metrics.record(&PoolEvent::Closed {
+            reason: CloseReason::IdleTimeout,
+        });
+
+        let stats = metrics.snapshot();
+
+        assert_eq!(stats.acquires, 3);
+        assert_eq!(stats.waits, 2);
+        assert_eq!(stats.wait_time.count(), 3);
+        assert_eq!(stats.wait_time.sum(), Duration::from_millis(10_020));
+        assert_eq!(
+            stats.wait_time.buckets()[0],
+            (Some(Duration::from_millis(1)), 1)
+        );
+        assert_eq!(
+            stats.wait_time.buckets()[3],
+            (Some(Duration::from_millis(25)), 1)
+        );
+        assert_eq!(stats.wait_time.buckets()[10], (None, 1));
+        assert_eq!(stats.closes.idle_timeout, 1);
+        assert_eq!(stats.closes.max_lifetime, 0);
+    }
+}
@@ -1,10 +1,15 @@
+use std::fmt::{self, Write};
 use std::ops::{Deref, DerefMut};
 
+use bytes::Buf;
+
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
+use crate::postgres::type_info::PgType;
 use crate::postgres::{PgConnection, PgTypeInfo, Postgres};
+use crate::type_info::TypeInfo;
 use crate::types::Type;
 
 #[derive(Default)]
@@ -50,6 +55,52 @@ impl<'q> Arguments<'q> for PgArguments {
         // encode the value into our buffer
         self.buffer.encode(value);
     }
+
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "${}", self.types.len())
+    }
+
+    fn format_values(&self) -> Vec<String> {
+        let mut buf: &[u8] = &self.buffer;
+
+        self.types
+            .iter()
+            .map(|ty| {
+                // every value is prefixed with its length, or -1 for NULL
+                let len = buf.get_i32();
+
+                if len < 0 {
+                    return "NULL".to_owned();
+                }
+
+                let (value, rest) = buf.split_at(len as usize);
+                buf = rest;
+
+                format_value(ty, value)
+            })
+            .collect()
+    }
+}
+
+// renders the common scalar types; all values are in the binary format
+fn format_value(ty: &PgTypeInfo, mut value: &[u8]) -> String {
+    match (&ty.0, value.len()) {
+        (PgType::Bool, 1) => (value[0] != 0).to_string(),
+        (PgType::Int2, 2) => value.get_i16().to_string(),
+        (PgType::Int4, 4) => value.get_i32().to_string(),
+        (PgType::Int8, 8) => value.get_i64().to_string(),
+        (PgType::Oid, 4) => value.get_u32().to_string(),
+        (PgType::Float4, 4) => value.get_f32().to_string(),
+        (PgType::Float8, 8) => value.get_f64().to_string(),
+
+        (PgType::Text, _)
+        | (PgType::Varchar, _)
+        | (PgType::Bpchar, _)
+        | (PgType::Name, _)
+        | (PgType::Unknown, _) => format!("{:?}", String::from_utf8_lossy(value)),
+
+        _ => format!("<{} bytes of {}>", value.len(), ty.name()),
+    }
 }
 
 impl PgArgumentBuffer {
@@ -111,3 +162,33 @@ impl DerefMut for PgArgumentBuffer {
         &mut self.buffer
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PgArguments;
+    use crate::arguments::Arguments;
+
+    #[test]
+    fn it_formats_values() {
+        let mut arguments = PgArguments::default();
+
+        arguments.add(10_i32);
+        arguments.add("O'Brien");
+        arguments.add(None::<i64>);
+        arguments.add(true);
+        arguments.add(1.5_f64);
+        arguments.add(&[1_u8, 2, 3][..]);
+
+        assert_eq!(
+            arguments.format_values(),
+            vec![
+                "10",
+                "\"O'Brien\"",
+                "NULL",
+                "true",
+                "1.5",
+                "<3 bytes of BYTEA>"
+            ]
+        );
+    }
+}
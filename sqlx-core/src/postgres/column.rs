@@ -0,0 +1,57 @@
+use crate::column::Column;
+use crate::describe;
+use crate::ext::ustr::UStr;
+use crate::postgres::{PgTypeInfo, Postgres};
+
+// Result column of a prepared statement
+// See RowDescription/Field for more information
+#[derive(Debug, Clone)]
+pub struct PgColumn {
+    pub(crate) ordinal: usize,
+    pub(crate) name: UStr,
+    pub(crate) type_info: PgTypeInfo,
+    pub(crate) relation_id: Option<i32>,
+    pub(crate) relation_attribute_no: Option<i16>,
+}
+
+impl PgColumn {
+    /// Returns the OID of the table this column comes from, if it is a column of a table.
+    pub fn relation_id(&self) -> Option<i32> {
+        self.relation_id
+    }
+
+    /// Returns the attribute number of this column in the table it comes from, if it is a
+    /// column of a table.
+    pub fn relation_attribute_no(&self) -> Option<i16> {
+        self.relation_attribute_no
+    }
+}
+
+impl crate::column::private_column::Sealed for PgColumn {}
+
+impl Column for PgColumn {
+    type Database = Postgres;
+
+    fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn type_info(&self) -> Option<&PgTypeInfo> {
+        Some(&self.type_info)
+    }
+
+    fn from_describe(ordinal: usize, column: describe::Column<Postgres>) -> Self {
+        PgColumn {
+            ordinal,
+            name: column.name.into(),
+            // PostgreSQL describes the type of every column
+            type_info: column.type_info.unwrap_or(PgTypeInfo::UNKNOWN),
+            relation_id: None,
+            relation_attribute_no: None,
+        }
+    }
+}
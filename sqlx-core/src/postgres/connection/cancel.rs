@@ -0,0 +1,82 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::postgres::connection::{stream::PgStream, tls};
+use crate::postgres::message::CancelRequest;
+use crate::postgres::PgConnectOptions;
+
+/// A handle to cancel the query running on a [`PgConnection`](super::PgConnection), returned
+/// by [`PgConnection::cancel_token`](super::PgConnection::cancel_token).
+///
+/// The token does not borrow the connection, so it can be sent to and used from another
+/// task while the connection is busy running a query.
+///
+/// ```rust,ignore
+/// let token = conn.cancel_token();
+///
+/// sqlx_rt::spawn(async move {
+///     sqlx_rt::sleep(Duration::from_secs(5)).await;
+///     let _ = token.cancel().await;
+/// });
+///
+/// // fails with `57014` (query_canceled) if it is still running after 5 seconds
+/// conn.execute("SELECT pg_sleep(60)").await?;
+/// ```
+#[derive(Clone)]
+pub struct PgCancelToken {
+    // the options of the host the connection is established with
+    options: Arc<PgConnectOptions>,
+
+    // process id and secret key of the backend, as sent in `BackendKeyData`
+    process_id: u32,
+    secret_key: u32,
+}
+
+impl PgCancelToken {
+    pub(super) fn new(options: &PgConnectOptions, process_id: u32, secret_key: u32) -> Self {
+        Self {
+            options: Arc::new(options.clone()),
+            process_id,
+            secret_key,
+        }
+    }
+
+    /// Asks the server to cancel the query currently running on the connection.
+    ///
+    /// The request is sent over a new connection to the server. Whether it succeeds is not
+    /// reported: if the query is canceled, it fails with the error `57014` (query_canceled);
+    /// if nothing was running, the request has no effect.
+    ///
+    /// The request is processed asynchronously by the server. If the query completes in the
+    /// meantime and the connection starts another one, that query may be canceled instead.
+    pub async fn cancel(&self) -> Result<(), Error> {
+        let mut stream = PgStream::connect(&self.options).await?;
+
+        // the request is sent over TLS if the connection itself would be
+        tls::maybe_upgrade(&mut stream, &self.options).await?;
+
+        stream
+            .send(CancelRequest {
+                process_id: self.process_id,
+                secret_key: self.secret_key,
+            })
+            .await?;
+
+        // the server sends nothing back and closes the connection once it has processed the
+        // request; wait for that so the query is canceled by the time we return
+        let _ = stream.read::<Bytes>(1).await;
+
+        Ok(())
+    }
+}
+
+impl Debug for PgCancelToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgCancelToken")
+            .field("process_id", &self.process_id)
+            .finish()
+    }
+}
@@ -10,8 +10,8 @@ use crate::describe::Column;
 use crate::error::Error;
 use crate::ext::ustr::UStr;
 use crate::postgres::message::{ParameterDescription, RowDescription};
-use crate::postgres::row::PgColumn;
 use crate::postgres::type_info::{PgCustomType, PgType, PgTypeKind};
+use crate::postgres::PgColumn;
 use crate::postgres::{PgArguments, PgConnection, PgTypeInfo, Postgres};
 use crate::query_as::{query_as, query_as_with};
 use crate::query_scalar::query_scalar;
@@ -55,6 +55,7 @@ impl PgConnection {
                 .await?;
 
             let column = PgColumn {
+                ordinal: index,
                 name: name.clone(),
                 type_info,
                 relation_id: field.relation_id,
@@ -1,19 +1,123 @@
 use hashbrown::HashMap;
+use rand::seq::SliceRandom;
 
 use crate::common::StatementCache;
+use crate::connection::Connection;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::io::Decode;
-use crate::postgres::connection::{sasl, stream::PgStream, tls};
+use crate::postgres::connection::{sasl, stream::PgStream, tls, PgCancelToken};
 use crate::postgres::message::{
     Authentication, BackendKeyData, MessageFormat, Password, ReadyForQuery, Startup,
 };
-use crate::postgres::{PgConnectOptions, PgConnection};
+use crate::postgres::{PgConnectOptions, PgConnection, PgTargetSessionAttrs};
+use crate::row::Row;
 
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.3
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.11
 
 impl PgConnection {
     pub(crate) async fn establish(options: &PgConnectOptions) -> Result<Self, Error> {
+        if options.other_hosts.is_empty()
+            && options.target_session_attrs == PgTargetSessionAttrs::Any
+        {
+            return Self::establish_host(options).await;
+        }
+
+        let mut hosts = vec![(options.host.clone(), options.port)];
+        hosts.extend(options.other_hosts.iter().cloned());
+
+        if options.load_balance_hosts {
+            hosts.shuffle(&mut rand::thread_rng());
+        }
+
+        // `prefer-standby` looks for a standby first
+        let attrs = match options.target_session_attrs {
+            PgTargetSessionAttrs::PreferStandby => PgTargetSessionAttrs::Standby,
+            attrs => attrs,
+        };
+
+        let mut error = None;
+
+        // the hosts that are up but do not satisfy `attrs`
+        let mut other_hosts = Vec::new();
+
+        for (host, port) in &hosts {
+            let options = options.clone().host(host).port(*port);
+
+            let mut conn = match Self::establish_host(&options).await {
+                Ok(conn) => conn,
+
+                Err(e) => {
+                    log::debug!("failed to connect to {}:{}: {}", host, port, e);
+                    error = Some(e);
+                    continue;
+                }
+            };
+
+            match conn.satisfies(attrs).await {
+                Ok(true) => return Ok(conn),
+
+                Ok(false) => {
+                    let _ = conn.close().await;
+
+                    other_hosts.push((host, port));
+
+                    error = Some(err_protocol!(
+                        "server at {}:{} does not satisfy target_session_attrs={:?}",
+                        host,
+                        port,
+                        options.target_session_attrs
+                    ));
+                }
+
+                Err(e) => error = Some(e),
+            }
+        }
+
+        // ... and settles for any server that is up if there is none
+        if options.target_session_attrs == PgTargetSessionAttrs::PreferStandby {
+            for (host, port) in other_hosts {
+                match Self::establish_host(&options.clone().host(host).port(*port)).await {
+                    Ok(conn) => return Ok(conn),
+
+                    Err(e) => {
+                        log::debug!("failed to connect to {}:{}: {}", host, port, e);
+                        error = Some(e);
+                    }
+                }
+            }
+        }
+
+        Err(error.expect("BUG: no host to connect to"))
+    }
+
+    /// Returns `true` if the server of this connection is the kind `attrs` asks for.
+    async fn satisfies(&mut self, attrs: PgTargetSessionAttrs) -> Result<bool, Error> {
+        Ok(match attrs {
+            PgTargetSessionAttrs::Any | PgTargetSessionAttrs::PreferStandby => true,
+
+            PgTargetSessionAttrs::ReadWrite | PgTargetSessionAttrs::ReadOnly => {
+                let read_only: String = self
+                    .fetch_one("SHOW transaction_read_only")
+                    .await?
+                    .try_get(0)?;
+
+                (read_only == "on") == (attrs == PgTargetSessionAttrs::ReadOnly)
+            }
+
+            PgTargetSessionAttrs::Primary | PgTargetSessionAttrs::Standby => {
+                let in_recovery: bool = self
+                    .fetch_one("SELECT pg_catalog.pg_is_in_recovery()")
+                    .await?
+                    .try_get(0)?;
+
+                in_recovery == (attrs == PgTargetSessionAttrs::Standby)
+            }
+        })
+    }
+
+    async fn establish_host(options: &PgConnectOptions) -> Result<Self, Error> {
         let mut stream = PgStream::connect(options).await?;
 
         // Upgrade to TLS if we were asked to and the server supports it
@@ -134,16 +238,19 @@ impl PgConnection {
 
         Ok(PgConnection {
             stream,
-            process_id,
-            secret_key,
+            cancel_token: PgCancelToken::new(options, process_id, secret_key),
             transaction_status,
             pending_ready_for_query_count: 0,
+            pending_cancel: None,
             next_statement_id: 1,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
             cache_type_oid: HashMap::new(),
             cache_type_info: HashMap::new(),
             scratch_row_columns: Default::default(),
             scratch_row_column_names: Default::default(),
+            log_settings: options.log_settings.clone(),
+            #[cfg(feature = "tracing")]
+            database: options.database.clone(),
         })
     }
 }
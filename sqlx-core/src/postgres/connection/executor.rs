@@ -1,27 +1,42 @@
 use either::Either;
+use futures_channel::oneshot;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
+use sqlx_rt::spawn;
+use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
+use crate::common::with_timeout;
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::logging::QueryLogger;
 use crate::postgres::message::{
     self, Bind, Close, CommandComplete, DataRow, Flush, MessageFormat, ParameterDescription, Parse,
     Query, RowDescription,
 };
 use crate::postgres::type_info::PgType;
-use crate::postgres::{PgArguments, PgConnection, PgRow, PgValueFormat, Postgres};
+use crate::postgres::{PgArguments, PgConnection, PgDone, PgRow, PgValueFormat, Postgres};
 
 async fn prepare(
     conn: &mut PgConnection,
     query: &str,
     arguments: &PgArguments,
+    persistent: bool,
 ) -> Result<u32, Error> {
-    let id = conn.next_statement_id;
-    conn.next_statement_id = conn.next_statement_id.wrapping_add(1);
+    let id = if persistent {
+        let id = conn.next_statement_id;
+
+        // skip over 0 on wrap-around as that is the ID of the unnamed statement
+        conn.next_statement_id = id.checked_add(1).unwrap_or(1);
+
+        id
+    } else {
+        // the unnamed statement is replaced by the next unnamed statement we prepare
+        0
+    };
 
     // build a list of type OIDs to send to the database in the PARSE command
     // we have not yet started the query sequence, so we are *safe* to cleanly make
@@ -56,10 +71,17 @@ async fn prepare(
     conn.stream.flush().await?;
 
     // indicates that the SQL query string is now successfully parsed and has semantic validity
-    let _: () = conn
-        .stream
-        .recv_expect(MessageFormat::ParseComplete)
-        .await?;
+    let parsed: Result<(), Error> = conn.stream.recv_expect(MessageFormat::ParseComplete).await;
+
+    if let Err(error) = parsed {
+        // the server discards every message after an error until it receives a [Sync],
+        // send one now so the connection can be used again for the next query
+        conn.stream.write(message::Sync);
+        conn.pending_ready_for_query_count += 1;
+        conn.stream.flush().await?;
+
+        return Err(error);
+    }
 
     Ok(id)
 }
@@ -90,12 +112,21 @@ async fn recv_desc_rows(conn: &mut PgConnection) -> Result<Option<RowDescription
 }
 
 impl PgConnection {
-    async fn prepare(&mut self, query: &str, arguments: &PgArguments) -> Result<u32, Error> {
+    async fn get_or_prepare(
+        &mut self,
+        query: &str,
+        arguments: &PgArguments,
+        persistent: bool,
+    ) -> Result<u32, Error> {
+        if !persistent {
+            return prepare(self, query, arguments, false).await;
+        }
+
         if let Some(statement) = self.cache_statement.get_mut(query) {
             return Ok(*statement);
         }
 
-        let statement = prepare(self, query, arguments).await?;
+        let statement = prepare(self, query, arguments, true).await?;
 
         if let Some(statement) = self.cache_statement.insert(query, statement) {
             self.stream.write(Close::Statement(statement));
@@ -111,14 +142,15 @@ impl PgConnection {
         query: &str,
         arguments: Option<PgArguments>,
         limit: u8,
-    ) -> Result<impl Stream<Item = Result<Either<u64, PgRow>, Error>> + '_, Error> {
+        persistent: bool,
+    ) -> Result<impl Stream<Item = Result<Either<PgDone, PgRow>, Error>> + '_, Error> {
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
 
         let format = if let Some(mut arguments) = arguments {
             // prepare the statement if this our first time executing it
             // always return the statement ID here
-            let statement = self.prepare(query, &arguments).await?;
+            let statement = self.get_or_prepare(query, &arguments, persistent).await?;
 
             // patch holes created during encoding
             arguments.buffer.patch_type_holes(self).await?;
@@ -172,9 +204,27 @@ impl PgConnection {
         self.pending_ready_for_query_count += 1;
         self.stream.flush().await?;
 
+        // the query is now running on the server; if the returned stream is dropped before it
+        // completes, the query is canceled instead of running to its end
+        let mut conn = CancelOnDrop {
+            conn: self,
+            running: true,
+        };
+
         Ok(try_stream! {
+            let mut rows = 0_usize;
+
             loop {
-                let message = self.stream.recv().await?;
+                let message = match conn.stream.recv().await {
+                    Ok(message) => message,
+
+                    Err(error) => {
+                        // the query failed and is no longer running
+                        conn.running = false;
+
+                        return Err(error);
+                    }
+                };
 
                 match message.format {
                     MessageFormat::BindComplete
@@ -188,7 +238,15 @@ impl PgConnection {
                         // a SQL command completed normally
                         let cc: CommandComplete = message.decode()?;
 
-                        r#yield!(Either::Left(cc.rows_affected()));
+                        if format == PgValueFormat::Binary {
+                            // a prepared statement is a single command; the server has
+                            // nothing left to run
+                            conn.running = false;
+                        }
+
+                        r#yield!(Either::Left(PgDone {
+                            rows_affected: cc.rows_affected(),
+                        }));
                     }
 
                     MessageFormat::EmptyQueryResponse => {
@@ -197,7 +255,7 @@ impl PgConnection {
 
                     MessageFormat::RowDescription => {
                         // indicates that a *new* set of rows are about to be returned
-                        self
+                        conn
                             .handle_row_description(Some(message.decode()?), false)
                             .await?;
                     }
@@ -208,16 +266,24 @@ impl PgConnection {
                         let row = PgRow {
                             data,
                             format,
-                            columns: Arc::clone(&self.scratch_row_columns),
-                            column_names: Arc::clone(&self.scratch_row_column_names),
+                            columns: Arc::clone(&conn.scratch_row_columns),
+                            column_names: Arc::clone(&conn.scratch_row_column_names),
                         };
 
+                        rows += 1;
+
+                        if rows == usize::from(limit) {
+                            // the caller has every row it asked for and will not wait for more
+                            conn.running = false;
+                        }
+
                         r#yield!(Either::Right(row));
                     }
 
                     MessageFormat::ReadyForQuery => {
                         // processing of the query string is complete
-                        self.handle_ready_for_query(message)?;
+                        conn.running = false;
+                        conn.handle_ready_for_query(message)?;
                         break;
                     }
 
@@ -233,24 +299,30 @@ impl PgConnection {
             Ok(())
         })
     }
-}
 
-impl<'c> Executor<'c> for &'c mut PgConnection {
-    type Database = Postgres;
-
-    fn fetch_many<'e, 'q: 'e, E: 'q>(
-        self,
+    // runs the query, returning at most `limit` rows (0 for no limit), within its timeout
+    fn fetch_stream<'e, 'q: 'e, E: 'q>(
+        &'e mut self,
         mut query: E,
-    ) -> BoxStream<'e, Result<Either<u64, PgRow>, Error>>
+        limit: u8,
+    ) -> impl Stream<Item = Result<Either<PgDone, PgRow>, Error>> + 'e
     where
-        'c: 'e,
-        E: Execute<'q, Self::Database>,
+        E: Execute<'q, Postgres>,
     {
         let s = query.query();
         let arguments = query.take_arguments();
+        let persistent = query.persistent();
+        let timeout = query.timeout();
+        let logger = QueryLogger::new(s, arguments.as_ref(), &self.log_settings);
 
-        Box::pin(try_stream! {
-            let s = self.run(s, arguments, 0).await?;
+        #[cfg(feature = "tracing")]
+        let logger = logger.span("postgresql", self.database.as_deref());
+
+        // a query that times out is canceled from a new connection
+        let cancel_token = self.cancel_token.clone();
+
+        let s = try_stream! {
+            let s = self.run(s, arguments, limit, persistent).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -258,22 +330,87 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
             }
 
             Ok(())
-        })
+        };
+
+        logger.instrument(with_timeout(s, timeout, async move {
+            cancel_token.cancel().await
+        }))
+    }
+}
+
+// the connection used by the stream of a running query, which is canceled on the server when
+// the stream is dropped before the query completes
+struct CancelOnDrop<'c> {
+    conn: &'c mut PgConnection,
+    running: bool,
+}
+
+impl Drop for CancelOnDrop<'_> {
+    fn drop(&mut self) {
+        if !self.running {
+            return;
+        }
+
+        // the connection waits for the cancel request to be processed before it sends another
+        // query, which would otherwise be canceled instead
+        let (sent, canceled) = oneshot::channel();
+        let token = self.conn.cancel_token.clone();
+
+        self.conn.pending_cancel = Some(canceled);
+
+        spawn(async move {
+            match token.cancel().await {
+                Ok(()) => {
+                    let _ = sent.send(());
+                }
+
+                Err(error) => {
+                    log::warn!("failed to cancel an abandoned query: {}", error);
+                }
+            }
+        });
+    }
+}
+
+impl Deref for CancelOnDrop<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        self.conn
+    }
+}
+
+impl DerefMut for CancelOnDrop<'_> {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        self.conn
+    }
+}
+
+impl<'c> Executor<'c> for &'c mut PgConnection {
+    type Database = Postgres;
+
+    fn fetch_many<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<PgDone, PgRow>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        Box::pin(self.fetch_stream(query, 0))
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
         self,
-        mut query: E,
+        query: E,
     ) -> BoxFuture<'e, Result<Option<PgRow>, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
-        let s = query.query();
-        let arguments = query.take_arguments();
+        let s = self.fetch_stream(query, 1);
 
         Box::pin(async move {
-            let s = self.run(s, arguments, 1).await?;
             pin_mut!(s);
 
             while let Some(s) = s.try_next().await? {
@@ -298,7 +435,11 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let s = query.query();
 
         Box::pin(async move {
-            let id = prepare(self, s, &Default::default()).await?;
+            self.wait_until_ready().await?;
+
+            // use the unnamed statement; caching it here would reuse the types inferred by the
+            // server, instead of those of the bound arguments, for later queries of this SQL
+            let id = prepare(self, s, &Default::default(), false).await?;
 
             self.stream.write(message::Describe::Statement(id));
             self.stream.write(Flush);
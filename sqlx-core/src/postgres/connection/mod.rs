@@ -1,12 +1,13 @@
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 
+use futures_channel::oneshot;
 use futures_core::future::BoxFuture;
 use futures_util::{FutureExt, TryFutureExt};
 use hashbrown::HashMap;
 
 use crate::common::StatementCache;
-use crate::connection::{Connect, Connection};
+use crate::connection::{Connect, Connection, LogSettings};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::ext::ustr::UStr;
@@ -15,9 +16,10 @@ use crate::postgres::connection::stream::PgStream;
 use crate::postgres::message::{
     Close, Flush, Message, MessageFormat, ReadyForQuery, Terminate, TransactionStatus,
 };
-use crate::postgres::row::PgColumn;
+use crate::postgres::PgColumn;
 use crate::postgres::{PgConnectOptions, PgTypeInfo, Postgres};
 
+mod cancel;
 pub(crate) mod describe;
 mod establish;
 mod executor;
@@ -25,6 +27,8 @@ mod sasl;
 mod stream;
 mod tls;
 
+pub use cancel::PgCancelToken;
+
 /// A connection to a PostgreSQL database.
 pub struct PgConnection {
     // underlying TCP or UDS stream,
@@ -32,15 +36,9 @@ pub struct PgConnection {
     // wrapped in a buffered stream
     pub(crate) stream: PgStream,
 
-    // process id of this backend
-    // used to send cancel requests
-    #[allow(dead_code)]
-    process_id: u32,
-
-    // secret key of this backend
+    // process id and secret key of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
-    secret_key: u32,
+    cancel_token: PgCancelToken,
 
     // sequence of statement IDs for use in preparing statements
     // in PostgreSQL, the statement is prepared to a user-supplied identifier
@@ -56,12 +54,23 @@ pub struct PgConnection {
     // number of ReadyForQuery messages that we are currently expecting
     pub(crate) pending_ready_for_query_count: usize,
 
+    // completes once the cancel request for a query whose results were abandoned has been
+    // processed by the server; dropped without a value if no request could be sent
+    pending_cancel: Option<oneshot::Receiver<()>>,
+
     // current transaction status
     transaction_status: TransactionStatus,
 
     // working memory for the active row's column information
     scratch_row_columns: Arc<Vec<PgColumn>>,
     scratch_row_column_names: Arc<HashMap<UStr, usize>>,
+
+    // how the statements run on this connection are logged
+    pub(crate) log_settings: LogSettings,
+
+    // the name of the database, reported in the spans of statements
+    #[cfg(feature = "tracing")]
+    pub(crate) database: Option<String>,
 }
 
 impl PgConnection {
@@ -71,8 +80,26 @@ impl PgConnection {
             self.stream.flush().await?;
         }
 
+        // the cancel request must be processed before we send anything else, so it cannot
+        // reach a later query
+        let canceled = match self.pending_cancel.take() {
+            Some(canceled) => canceled.await.is_ok(),
+            None => false,
+        };
+
         while self.pending_ready_for_query_count > 0 {
-            let message = self.stream.recv().await?;
+            let message = match self.stream.recv().await {
+                Ok(message) => message,
+
+                // the error of the query that was canceled after its stream was dropped;
+                // nobody is waiting for it
+                Err(Error::Database(error)) if canceled => {
+                    log::debug!("discarding the error of a canceled query: {}", error);
+                    continue;
+                }
+
+                Err(error) => return Err(error),
+            };
 
             if let MessageFormat::ReadyForQuery = message.format {
                 self.handle_ready_for_query(message)?;
@@ -91,6 +118,22 @@ impl PgConnection {
     }
 }
 
+impl PgConnection {
+    /// Returns a token that cancels the query running on this connection.
+    ///
+    /// The token can be used from another task while this connection is busy.
+    ///
+    /// A query is also canceled when the stream or future waiting for its results is dropped
+    /// before it completes, e.g. a [`fetch`] stream of which only the first rows were read.
+    /// The cancel request is sent from a background task as soon as the stream is dropped; the
+    /// connection waits for it to be processed before it runs the next query.
+    ///
+    /// [`fetch`]: crate::executor::Executor::fetch
+    pub fn cancel_token(&self) -> PgCancelToken {
+        self.cancel_token.clone()
+    }
+}
+
 impl Debug for PgConnection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("PgConnection").finish()
@@ -120,6 +163,10 @@ impl Connection for PgConnection {
         self.execute("/* SQLx ping */").map_ok(|_| ()).boxed()
     }
 
+    fn set_logging(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+
     fn cached_statements_size(&self) -> usize {
         self.cache_statement.len()
     }
@@ -149,7 +196,8 @@ impl Connection for PgConnection {
 
     #[doc(hidden)]
     fn should_flush(&self) -> bool {
-        !self.stream.wbuf.is_empty()
+        // the results of a canceled query are discarded when the connection is flushed
+        !self.stream.wbuf.is_empty() || self.pending_cancel.is_some()
     }
 
     #[doc(hidden)]
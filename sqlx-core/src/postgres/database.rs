@@ -1,7 +1,9 @@
 use crate::database::{Database, HasArguments, HasStatementCache, HasValueRef};
 use crate::postgres::arguments::PgArgumentBuffer;
 use crate::postgres::value::{PgValue, PgValueRef};
-use crate::postgres::{PgArguments, PgConnection, PgRow, PgTransactionManager, PgTypeInfo};
+use crate::postgres::{
+    PgArguments, PgColumn, PgConnection, PgDone, PgRow, PgTransactionManager, PgTypeInfo,
+};
 
 /// PostgreSQL database driver.
 #[derive(Debug)]
@@ -14,6 +16,10 @@ impl Database for Postgres {
 
     type Row = PgRow;
 
+    type Done = PgDone;
+
+    type Column = PgColumn;
+
     type TypeInfo = PgTypeInfo;
 
     type Value = PgValue;
@@ -4,7 +4,7 @@ use std::fmt::{self, Debug, Display, Formatter};
 use atoi::atoi;
 use smallvec::alloc::borrow::Cow;
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, ErrorKind};
 use crate::postgres::message::{Notice, PgSeverity};
 
 /// An error returned from the PostgreSQL database.
@@ -14,6 +14,7 @@ pub struct PgDatabaseError(pub(crate) Notice);
 // https://www.postgresql.org/docs/current/protocol-error-fields.html
 
 impl PgDatabaseError {
+    /// The severity of the error, from the non-localized `V` field when the server sends it.
     #[inline]
     pub fn severity(&self) -> PgSeverity {
         self.0.severity()
@@ -170,6 +171,36 @@ impl DatabaseError for PgDatabaseError {
         Some(Cow::Borrowed(self.code()))
     }
 
+    fn kind(&self) -> ErrorKind {
+        // https://www.postgresql.org/docs/current/errcodes-appendix.html
+        match self.code() {
+            "23505" => ErrorKind::UniqueViolation,
+            "23503" => ErrorKind::ForeignKeyViolation,
+            "23502" => ErrorKind::NotNullViolation,
+            "23514" => ErrorKind::CheckViolation,
+            "40001" => ErrorKind::SerializationFailure,
+            "40P01" => ErrorKind::Deadlock,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        let code = self.code();
+
+        // class 08 — connection exception
+        code.starts_with("08")
+            // admin_shutdown, crash_shutdown, cannot_connect_now
+            || code == "57P01"
+            || code == "57P02"
+            || code == "57P03"
+            // too_many_connections
+            || code == "53300"
+    }
+
+    fn constraint(&self) -> Option<&str> {
+        self.constraint()
+    }
+
     #[doc(hidden)]
     fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
         self
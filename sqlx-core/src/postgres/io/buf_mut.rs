@@ -27,12 +27,14 @@ impl PgBufMutExt for Vec<u8> {
         self[offset..(offset + 4)].copy_from_slice(&size.to_be_bytes());
     }
 
-    // writes a statement name by ID
+    // writes a statement name by ID, the ID 0 is used for the unnamed statement
     #[inline]
     fn put_statement_name(&mut self, id: u32) {
-        self.extend(b"sqlx_s_");
+        if id != 0 {
+            self.extend(b"sqlx_s_");
 
-        itoa::write(&mut *self, id).unwrap();
+            itoa::write(&mut *self, id).unwrap();
+        }
 
         self.push(0);
     }
@@ -11,7 +11,7 @@ use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::pool::{Pool, PoolConnection};
 use crate::postgres::message::{MessageFormat, Notification};
-use crate::postgres::{PgConnection, PgRow, Postgres};
+use crate::postgres::{PgConnection, PgDone, PgRow, Postgres};
 use either::Either;
 
 /// A stream of asynchronous notifications from Postgres.
@@ -198,7 +198,7 @@ impl<'c> Executor<'c> for &'c mut PgListener {
     fn fetch_many<'e, 'q: 'e, E: 'q>(
         self,
         query: E,
-    ) -> BoxStream<'e, Result<Either<u64, PgRow>, Error>>
+    ) -> BoxStream<'e, Result<Either<PgDone, PgRow>, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
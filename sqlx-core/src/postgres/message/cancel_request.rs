@@ -0,0 +1,37 @@
+use crate::io::Encode;
+
+// To issue a cancel request, the frontend opens a new connection to the server and sends a
+// CancelRequest message, rather than the StartupMessage message that would ordinarily be sent
+// across a new connection. The server will process this request and then close the connection.
+
+pub struct CancelRequest {
+    /// The process ID of the target backend.
+    pub process_id: u32,
+
+    /// The secret key for the target backend.
+    pub secret_key: u32,
+}
+
+impl Encode<'_> for CancelRequest {
+    #[inline]
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.extend(&16_u32.to_be_bytes());
+        buf.extend(&(((1234 << 16) | 5678) as u32).to_be_bytes());
+        buf.extend(&self.process_id.to_be_bytes());
+        buf.extend(&self.secret_key.to_be_bytes());
+    }
+}
+
+#[test]
+fn test_encode_cancel_request() {
+    const EXPECTED: &[u8] = b"\x00\x00\x00\x10\x04\xd2\x16\x2e\x00\x00\x30\x39\xde\xad\xbe\xef";
+
+    let mut buf = Vec::new();
+    CancelRequest {
+        process_id: 12345,
+        secret_key: 0xdead_beef,
+    }
+    .encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
@@ -1,8 +1,10 @@
 //! **PostgreSQL** database driver.
 
 mod arguments;
+mod column;
 mod connection;
 mod database;
+mod done;
 mod error;
 mod io;
 mod listener;
@@ -15,12 +17,14 @@ pub mod types;
 mod value;
 
 pub use arguments::{PgArgumentBuffer, PgArguments};
-pub use connection::PgConnection;
+pub use column::PgColumn;
+pub use connection::{PgCancelToken, PgConnection};
 pub use database::Postgres;
+pub use done::PgDone;
 pub use error::{PgDatabaseError, PgErrorPosition};
 pub use listener::{PgListener, PgNotification};
 pub use message::PgSeverity;
-pub use options::{PgConnectOptions, PgSslMode};
+pub use options::{PgConnectOptions, PgSslMode, PgTargetSessionAttrs};
 pub use row::PgRow;
 pub use transaction::PgTransactionManager;
 pub use type_info::PgTypeInfo;
@@ -6,7 +6,7 @@ use crate::postgres::message::Query;
 use crate::postgres::{PgConnection, Postgres};
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    TransactionManager, TransactionOptions,
 };
 
 /// Implementation of [`TransactionManager`] for PostgreSQL.
@@ -15,9 +15,17 @@ pub struct PgTransactionManager;
 impl TransactionManager for PgTransactionManager {
     type Database = Postgres;
 
-    fn begin(conn: &mut PgConnection, depth: usize) -> BoxFuture<'_, Result<(), Error>> {
+    fn begin(
+        conn: &mut PgConnection,
+        depth: usize,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
-            conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
+            if depth == 0 && options != TransactionOptions::default() {
+                conn.execute(&*begin_transaction_sql(options)).await?;
+            } else {
+                conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
+            }
 
             Ok(())
         })
@@ -45,3 +53,47 @@ impl TransactionManager for PgTransactionManager {
             .write(Query(&rollback_ansi_transaction_sql(depth)));
     }
 }
+
+fn begin_transaction_sql(options: TransactionOptions) -> String {
+    let mut modes = Vec::new();
+
+    if let Some(isolation) = options.isolation {
+        modes.push(format!("ISOLATION LEVEL {}", isolation.as_sql()));
+    }
+
+    if let Some(access_mode) = options.access_mode {
+        modes.push(access_mode.as_sql().to_owned());
+    }
+
+    if options.deferrable {
+        modes.push("DEFERRABLE".to_owned());
+    }
+
+    format!("BEGIN {}", modes.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::begin_transaction_sql;
+    use crate::transaction::{AccessMode, IsolationLevel, TransactionOptions};
+
+    #[test]
+    fn it_builds_begin_with_options() {
+        assert_eq!(
+            begin_transaction_sql(TransactionOptions {
+                isolation: Some(IsolationLevel::Serializable),
+                access_mode: Some(AccessMode::ReadOnly),
+                deferrable: true,
+            }),
+            "BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE"
+        );
+
+        assert_eq!(
+            begin_transaction_sql(TransactionOptions {
+                isolation: Some(IsolationLevel::ReadCommitted),
+                ..Default::default()
+            }),
+            "BEGIN ISOLATION LEVEL READ COMMITTED"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::stream::BoxStream;
@@ -17,6 +18,8 @@ pub struct Query<'q, DB: Database, A> {
     pub(crate) query: &'q str,
     pub(crate) arguments: Option<A>,
     pub(crate) database: PhantomData<DB>,
+    pub(crate) persistent: bool,
+    pub(crate) timeout: Option<Duration>,
 }
 
 /// SQL query that will map its results to owned Rust types.
@@ -47,6 +50,16 @@ where
     fn take_arguments(&mut self) -> Option<<DB as HasArguments<'q>>::Arguments> {
         self.arguments.take().map(IntoArguments::into_arguments)
     }
+
+    #[inline]
+    fn persistent(&self) -> bool {
+        self.persistent
+    }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
 }
 
 impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
@@ -72,6 +85,38 @@ where
     DB: Database,
     A: 'q + IntoArguments<'q, DB>,
 {
+    /// If `true` (the default), the statement is prepared once and kept in the statement cache
+    /// of the connection, to be reused by every later execution of the same SQL on that
+    /// connection.
+    ///
+    /// If `false`, the statement is prepared as a one-off (unnamed) statement that is not cached.
+    /// This avoids evicting frequently used statements from the cache with dynamic queries that
+    /// are executed only once, and is required when connecting through a proxy that does not
+    /// support named prepared statements, such as PgBouncer in transaction mode.
+    ///
+    /// This has no effect on drivers that do not cache prepared statements (MSSQL).
+    #[inline]
+    pub fn persistent(mut self, value: bool) -> Self {
+        self.persistent = value;
+        self
+    }
+
+    /// Set the maximum amount of time the query may run for.
+    ///
+    /// If the query has not completed when the timeout elapses, the driver asks the database
+    /// to stop it, discards the rest of its results so the connection can be used again, and
+    /// returns [`Error::QueryTimedOut`]. Rows that were already returned are not affected.
+    ///
+    /// The query is stopped with a cancel request in Postgres, `KILL QUERY` from a separate
+    /// connection in MySQL, an attention signal in MSSQL and `sqlite3_interrupt` in SQLite.
+    /// As the database may have completed the query in the meantime, its changes are not
+    /// necessarily undone.
+    #[inline]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Map each row in the result to another type.
     ///
     /// See [`try_map`](Query::try_map) for a fallible version of this method.
@@ -103,7 +148,7 @@ where
 
     /// Execute the query and return the total number of rows affected.
     #[inline]
-    pub async fn execute<'e, 'c: 'e, E>(self, executor: E) -> Result<u64, Error>
+    pub async fn execute<'e, 'c: 'e, E>(self, executor: E) -> Result<DB::Done, Error>
     where
         'q: 'e,
         A: 'e,
@@ -114,7 +159,10 @@ where
 
     /// Execute multiple queries and return the rows affected from each query, in a stream.
     #[inline]
-    pub async fn execute_many<'e, 'c: 'e, E>(self, executor: E) -> BoxStream<'e, Result<u64, Error>>
+    pub async fn execute_many<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+    ) -> BoxStream<'e, Result<DB::Done, Error>>
     where
         'q: 'e,
         A: 'e,
@@ -140,7 +188,7 @@ where
     pub fn fetch_many<'e, 'c: 'e, E>(
         self,
         executor: E,
-    ) -> BoxStream<'e, Result<Either<u64, DB::Row>, Error>>
+    ) -> BoxStream<'e, Result<Either<DB::Done, DB::Row>, Error>>
     where
         'q: 'e,
         A: 'e,
@@ -197,6 +245,16 @@ where
     fn take_arguments(&mut self) -> Option<<DB as HasArguments<'q>>::Arguments> {
         self.inner.take_arguments()
     }
+
+    #[inline]
+    fn persistent(&self) -> bool {
+        Execute::persistent(&self.inner)
+    }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        Execute::timeout(&self.inner)
+    }
 }
 
 impl<'q, DB, F, O, A> Map<'q, DB, F, A>
@@ -230,7 +288,7 @@ where
     pub fn fetch_many<'e, 'c: 'e, E>(
         self,
         executor: E,
-    ) -> BoxStream<'e, Result<Either<u64, O>, Error>>
+    ) -> BoxStream<'e, Result<Either<DB::Done, O>, Error>>
     where
         'q: 'e,
         E: 'e + Executor<'c, Database = DB>,
@@ -312,6 +370,8 @@ where
         database: PhantomData,
         arguments: Some(Default::default()),
         query: sql,
+        persistent: true,
+        timeout: None,
     }
 }
 
@@ -326,5 +386,7 @@ where
         database: PhantomData,
         arguments: Some(arguments),
         query: sql,
+        persistent: true,
+        timeout: None,
     }
 }
@@ -0,0 +1,398 @@
+//! Runtime query-builder API.
+
+use std::fmt::Display;
+use std::fmt::Write;
+use std::marker::PhantomData;
+
+use crate::arguments::Arguments;
+use crate::database::{Database, HasArguments};
+use crate::encode::Encode;
+use crate::from_row::FromRow;
+use crate::query::Query;
+use crate::query_as::QueryAs;
+use crate::types::Type;
+
+/// A builder type for constructing queries at runtime.
+///
+/// Bind parameters are pushed with [`push_bind`](#method.push_bind), which writes the
+/// placeholder syntax of the database (`?` for most SQL flavors, `$N` for PostgreSQL, `@pN` for
+/// MSSQL) and collects the value into the arguments of the query.
+///
+/// The [`Any`](crate::any::Any) driver does not know the database until the query is executed;
+/// build its queries with [`with_arguments`](#method.with_arguments) and
+/// [`AnyArguments::new`](crate::any::AnyArguments::new) to write the placeholders of the
+/// database of the connection. Otherwise it writes `?`, and executing the query on PostgreSQL
+/// or MSSQL returns [`Error::InvalidArgument`](crate::error::Error::InvalidArgument).
+pub struct QueryBuilder<'args, DB>
+where
+    DB: Database,
+{
+    query: String,
+    init_len: usize,
+    arguments: Option<<DB as HasArguments<'args>>::Arguments>,
+
+    // the arguments to start over with on `reset`
+    empty_arguments: <DB as HasArguments<'args>>::Arguments,
+}
+
+impl<'args, DB> QueryBuilder<'args, DB>
+where
+    DB: Database,
+{
+    /// Start building a query with an initial SQL fragment, which may be an empty string.
+    pub fn new(init: impl Into<String>) -> Self {
+        Self::with_arguments(init, Default::default())
+    }
+
+    /// Start building a query with an initial SQL fragment and the arguments to add the bind
+    /// parameters to.
+    ///
+    /// ```rust,ignore
+    /// let mut builder = QueryBuilder::<Any>::with_arguments(
+    ///     "SELECT * FROM users WHERE id = ",
+    ///     AnyArguments::new(conn.kind()),
+    /// );
+    ///
+    /// builder.push_bind(10_i32);
+    ///
+    /// // SELECT * FROM users WHERE id = $1 (on PostgreSQL)
+    /// ```
+    pub fn with_arguments(
+        init: impl Into<String>,
+        arguments: <DB as HasArguments<'args>>::Arguments,
+    ) -> Self {
+        let init = init.into();
+
+        QueryBuilder {
+            init_len: init.len(),
+            query: init,
+            empty_arguments: arguments.new_like(),
+            arguments: Some(arguments),
+        }
+    }
+
+    #[inline]
+    fn sanity_check(&self) {
+        assert!(
+            self.arguments.is_some(),
+            "QueryBuilder must be reset before reuse after `.build()`"
+        );
+    }
+
+    /// Append a SQL fragment to the query.
+    ///
+    /// The fragment is written as-is; **never** push untrusted input with this method as that
+    /// opens the query to SQL injection. Use [`push_bind`](#method.push_bind) for values.
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        self.sanity_check();
+
+        write!(self.query, "{}", sql).expect("error formatting `sql`");
+
+        self
+    }
+
+    /// Push a bind parameter placeholder (`?`, `$N` or `@pN`, depending on the database) and
+    /// bind a value to it.
+    ///
+    /// Note that databases limit the number of bind parameters in a single query; PostgreSQL
+    /// and MySQL allow at most 65535, SQLite 32766 (999 before 3.32), and MSSQL 2100.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: 'args + Encode<'args, DB> + Type<DB>,
+    {
+        self.sanity_check();
+
+        let arguments = self
+            .arguments
+            .as_mut()
+            .expect("BUG: Arguments taken already");
+
+        arguments.add(value);
+        arguments
+            .format_placeholder(&mut self.query)
+            .expect("error in format_placeholder");
+
+        self
+    }
+
+    /// Start a list separated by `separator`.
+    ///
+    /// The returned type exposes identical [`push`](Separated::push) and
+    /// [`push_bind`](Separated::push_bind) methods which push `separator` to the query
+    /// before their normal behavior. [`push_unseparated`](Separated::push_unseparated) and
+    /// [`push_bind_unseparated`](Separated::push_bind_unseparated) are also provided to push
+    /// a SQL fragment without the separator.
+    ///
+    /// ```rust,ignore
+    /// let mut builder = QueryBuilder::<Postgres>::new("SELECT * FROM users WHERE id IN (");
+    /// let mut separated = builder.separated(", ");
+    ///
+    /// for id in &[1, 2, 3] {
+    ///     separated.push_bind(*id);
+    /// }
+    ///
+    /// separated.push_unseparated(")");
+    ///
+    /// // SELECT * FROM users WHERE id IN ($1, $2, $3)
+    /// ```
+    pub fn separated<'qb, Sep>(&'qb mut self, separator: Sep) -> Separated<'qb, 'args, DB, Sep>
+    where
+        'args: 'qb,
+        Sep: Display,
+    {
+        self.sanity_check();
+
+        Separated {
+            query_builder: self,
+            separator,
+            push_separator: false,
+        }
+    }
+
+    /// Push a `VALUES` clause where each item in `tuples` represents a tuple/row in the clause.
+    ///
+    /// This can be used to construct a bulk `INSERT` statement. `push_tuple` is called once
+    /// per item to push the values of that row, which are separated by commas and wrapped in
+    /// parentheses.
+    ///
+    /// ```rust,ignore
+    /// let mut builder = QueryBuilder::<Postgres>::new("INSERT INTO users(id, username) ");
+    ///
+    /// builder.push_values(users, |mut row, user| {
+    ///     row.push_bind(user.id).push_bind(user.username);
+    /// });
+    ///
+    /// // INSERT INTO users(id, username) VALUES ($1, $2), ($3, $4), ...
+    /// ```
+    pub fn push_values<I, F>(&mut self, tuples: I, mut push_tuple: F) -> &mut Self
+    where
+        I: IntoIterator,
+        F: FnMut(Separated<'_, 'args, DB, &'static str>, I::Item),
+    {
+        self.sanity_check();
+
+        self.push("VALUES ");
+
+        let mut separated = self.separated(", ");
+
+        for tuple in tuples {
+            separated.push("(");
+
+            // use a `Separated` with a separate (hah) internal state
+            push_tuple(separated.query_builder.separated(", "), tuple);
+
+            separated.push_unseparated(")");
+        }
+
+        self
+    }
+
+    /// Produce an executable query from this builder.
+    ///
+    /// The arguments are moved into the returned query; the builder must be
+    /// [`reset`](#method.reset) before it can be used again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this builder has already been built and not reset.
+    pub fn build(&mut self) -> Query<'_, DB, <DB as HasArguments<'args>>::Arguments> {
+        self.sanity_check();
+
+        Query {
+            query: &self.query,
+            arguments: self.arguments.take(),
+            database: PhantomData,
+            persistent: true,
+            timeout: None,
+        }
+    }
+
+    /// Produce an executable query from this builder that maps its results to `O` using
+    /// [`FromRow`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this builder has already been built and not reset.
+    pub fn build_query_as<'q, O>(
+        &'q mut self,
+    ) -> QueryAs<'q, DB, O, <DB as HasArguments<'args>>::Arguments>
+    where
+        O: for<'r> FromRow<'r, DB::Row>,
+    {
+        QueryAs {
+            inner: self.build(),
+            output: PhantomData,
+        }
+    }
+
+    /// Reset this builder to its initial SQL fragment and an empty set of arguments.
+    pub fn reset(&mut self) -> &mut Self {
+        self.query.truncate(self.init_len);
+        self.arguments = Some(self.empty_arguments.new_like());
+
+        self
+    }
+
+    /// Get the current SQL of this builder.
+    pub fn sql(&self) -> &str {
+        &self.query
+    }
+
+    /// Deconstruct this builder, returning the SQL it has built so far.
+    pub fn into_sql(self) -> String {
+        self.query
+    }
+}
+
+/// A wrapper around a [`QueryBuilder`] that pushes a separator between the SQL fragments and
+/// bind parameters that are pushed to it.
+///
+/// Returned by [`QueryBuilder::separated`].
+pub struct Separated<'qb, 'args, DB, Sep>
+where
+    DB: Database,
+{
+    query_builder: &'qb mut QueryBuilder<'args, DB>,
+    separator: Sep,
+    push_separator: bool,
+}
+
+impl<'qb, 'args: 'qb, DB, Sep> Separated<'qb, 'args, DB, Sep>
+where
+    DB: Database,
+    Sep: Display,
+{
+    /// Push the separator if this is not the first push, then the SQL fragment.
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        if self.push_separator {
+            self.query_builder
+                .push(format_args!("{}{}", self.separator, sql));
+        } else {
+            self.query_builder.push(sql);
+            self.push_separator = true;
+        }
+
+        self
+    }
+
+    /// Push a SQL fragment without a separator.
+    pub fn push_unseparated(&mut self, sql: impl Display) -> &mut Self {
+        self.query_builder.push(sql);
+        self
+    }
+
+    /// Push the separator if this is not the first push, then a bind parameter.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: 'args + Encode<'args, DB> + Type<DB>,
+    {
+        if self.push_separator {
+            self.query_builder.push(&self.separator);
+        }
+
+        self.query_builder.push_bind(value);
+        self.push_separator = true;
+
+        self
+    }
+
+    /// Push a bind parameter without a separator.
+    pub fn push_bind_unseparated<T>(&mut self, value: T) -> &mut Self
+    where
+        T: 'args + Encode<'args, DB> + Type<DB>,
+    {
+        self.query_builder.push_bind(value);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use super::*;
+    use crate::postgres::Postgres;
+
+    #[test]
+    fn it_writes_postgres_placeholders() {
+        let mut qb: QueryBuilder<'_, Postgres> = QueryBuilder::new("SELECT * FROM users WHERE ");
+
+        qb.push("id = ").push_bind(10_i32);
+        qb.push(" AND name = ").push_bind("alice");
+
+        assert_eq!(qb.sql(), "SELECT * FROM users WHERE id = $1 AND name = $2");
+    }
+
+    #[test]
+    fn it_separates() {
+        let mut qb: QueryBuilder<'_, Postgres> =
+            QueryBuilder::new("SELECT * FROM users WHERE id IN (");
+
+        let mut separated = qb.separated(", ");
+
+        for id in &[1_i32, 2, 3] {
+            separated.push_bind(*id);
+        }
+
+        separated.push_unseparated(")");
+
+        assert_eq!(qb.sql(), "SELECT * FROM users WHERE id IN ($1, $2, $3)");
+    }
+
+    #[test]
+    fn it_pushes_values() {
+        let mut qb: QueryBuilder<'_, Postgres> = QueryBuilder::new("INSERT INTO users(id, name) ");
+
+        qb.push_values(vec![(1_i32, "alice"), (2, "bob")], |mut row, (id, name)| {
+            row.push_bind(id).push_bind(name);
+        });
+
+        assert_eq!(
+            qb.sql(),
+            "INSERT INTO users(id, name) VALUES ($1, $2), ($3, $4)"
+        );
+    }
+
+    #[test]
+    fn it_resets() {
+        let mut qb: QueryBuilder<'_, Postgres> = QueryBuilder::new("SELECT ");
+
+        qb.push_bind(1_i32);
+        let query = qb.build();
+
+        assert_eq!(query.query, "SELECT $1");
+        assert!(query.arguments.is_some());
+
+        qb.reset();
+        qb.push_bind(2_i32);
+
+        assert_eq!(qb.sql(), "SELECT $1");
+    }
+
+    #[cfg(feature = "any")]
+    #[test]
+    fn it_writes_the_placeholders_of_the_any_kind() {
+        use crate::any::{Any, AnyArguments, AnyKind};
+
+        let mut qb: QueryBuilder<'_, Any> =
+            QueryBuilder::with_arguments("SELECT ", AnyArguments::new(AnyKind::Postgres));
+
+        qb.push_bind(1_i32).push(", ").push_bind(2_i32);
+
+        assert_eq!(qb.sql(), "SELECT $1, $2");
+
+        // the database is kept across a reset
+        let _ = qb.build();
+        qb.reset();
+        qb.push_bind(3_i32);
+
+        assert_eq!(qb.sql(), "SELECT $1");
+    }
+
+    #[test]
+    #[should_panic(expected = "QueryBuilder must be reset before reuse after `.build()`")]
+    fn it_panics_when_reused_without_reset() {
+        let mut qb: QueryBuilder<'_, Postgres> = QueryBuilder::new("SELECT ");
+
+        let _ = qb.build();
+        qb.push("1");
+    }
+}
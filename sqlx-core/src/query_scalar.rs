@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use either::Either;
 use futures_core::stream::BoxStream;
 use futures_util::{StreamExt, TryFutureExt, TryStreamExt};
@@ -31,6 +33,16 @@ where
     fn take_arguments(&mut self) -> Option<<DB as HasArguments<'q>>::Arguments> {
         self.inner.take_arguments()
     }
+
+    #[inline]
+    fn persistent(&self) -> bool {
+        Execute::persistent(&self.inner)
+    }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        Execute::timeout(&self.inner)
+    }
 }
 
 impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Arguments> {
@@ -52,6 +64,24 @@ where
     A: 'q + IntoArguments<'q, DB>,
     (O,): Send + Unpin + for<'r> FromRow<'r, DB::Row>,
 {
+    /// If `true` (the default), the statement is prepared once and kept in the statement cache.
+    ///
+    /// See [`Query::persistent`](crate::query::Query::persistent).
+    #[inline]
+    pub fn persistent(mut self, value: bool) -> Self {
+        self.inner = self.inner.persistent(value);
+        self
+    }
+
+    /// Set the maximum amount of time the query may run for.
+    ///
+    /// See [`Query::timeout`](crate::query::Query::timeout).
+    #[inline]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+
     /// Execute the query and return the generated results as a stream.
     #[inline]
     pub fn fetch<'e, 'c: 'e, E>(self, executor: E) -> BoxStream<'e, Result<O, Error>>
@@ -71,7 +101,7 @@ where
     pub fn fetch_many<'e, 'c: 'e, E>(
         self,
         executor: E,
-    ) -> BoxStream<'e, Result<Either<u64, O>, Error>>
+    ) -> BoxStream<'e, Result<Either<DB::Done, O>, Error>>
     where
         'q: 'e,
         E: 'e + Executor<'c, Database = DB>,
@@ -77,6 +77,44 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
     /// Returns the number of columns in this row.
     fn len(&self) -> usize;
 
+    /// Returns the metadata of the columns in this row, in order.
+    fn columns(&self) -> &[<Self::Database as Database>::Column];
+
+    /// Gets the metadata of the column at `index`.
+    ///
+    /// A string index can be used to access a column by name and a `usize` index
+    /// can be used to access a column by position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds or the column could not be found.
+    /// See [`try_column`](#method.try_column) for a non-panicking version.
+    ///
+    #[inline]
+    fn column<I>(&self, index: I) -> &<Self::Database as Database>::Column
+    where
+        I: ColumnIndex<Self>,
+    {
+        self.try_column(index).unwrap()
+    }
+
+    /// Gets the metadata of the column at `index`.
+    ///
+    /// # Errors
+    ///
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///
+    /// [`ColumnNotFound`]: crate::Error::ColumnNotFound
+    /// [`ColumnIndexOutOfBounds`]: crate::Error::ColumnIndexOutOfBounds
+    ///
+    fn try_column<I>(&self, index: I) -> Result<&<Self::Database as Database>::Column, Error>
+    where
+        I: ColumnIndex<Self>,
+    {
+        Ok(&self.columns()[index.index(self)?])
+    }
+
     /// Index into the database row and decode a single value.
     ///
     /// A string index can be used to access a column by name and a `usize` index
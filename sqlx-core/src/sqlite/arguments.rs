@@ -39,6 +39,20 @@ impl<'q> Arguments<'q> for SqliteArguments<'q> {
             self.values.push(SqliteArgumentValue::Null);
         }
     }
+
+    fn format_values(&self) -> Vec<String> {
+        self.values
+            .iter()
+            .map(|value| match value {
+                SqliteArgumentValue::Null => "NULL".to_owned(),
+                SqliteArgumentValue::Text(text) => format!("{:?}", text),
+                SqliteArgumentValue::Blob(blob) => format!("<{} bytes of BLOB>", blob.len()),
+                SqliteArgumentValue::Double(value) => value.to_string(),
+                SqliteArgumentValue::Int(value) => value.to_string(),
+                SqliteArgumentValue::Int64(value) => value.to_string(),
+            })
+            .collect()
+    }
 }
 
 impl SqliteArguments<'_> {
@@ -0,0 +1,39 @@
+use crate::column::Column;
+use crate::describe;
+use crate::ext::ustr::UStr;
+use crate::sqlite::{Sqlite, SqliteTypeInfo};
+
+#[derive(Debug, Clone)]
+pub struct SqliteColumn {
+    pub(crate) ordinal: usize,
+    pub(crate) name: UStr,
+    pub(crate) type_info: Option<SqliteTypeInfo>,
+}
+
+impl crate::column::private_column::Sealed for SqliteColumn {}
+
+impl Column for SqliteColumn {
+    type Database = Sqlite;
+
+    fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    // this is the declared type of the column in its table, which is not
+    // necessarily the type of every value stored in the column
+    fn type_info(&self) -> Option<&SqliteTypeInfo> {
+        self.type_info.as_ref()
+    }
+
+    fn from_describe(ordinal: usize, column: describe::Column<Sqlite>) -> Self {
+        SqliteColumn {
+            ordinal,
+            name: column.name.into(),
+            type_info: column.type_info,
+        }
+    }
+}
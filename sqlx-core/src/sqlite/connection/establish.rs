@@ -91,6 +91,10 @@ pub(super) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         worker: StatementWorker::new(),
         statements: StatementCache::new(options.statement_cache_capacity),
         statement: None,
+        scratch_row_columns: Default::default(),
         scratch_row_column_names: Default::default(),
+        log_settings: options.log_settings.clone(),
+        #[cfg(feature = "tracing")]
+        database: Some(options.filename.to_string_lossy().into_owned()),
     })
 }
@@ -11,9 +11,12 @@ use crate::describe::{Column, Describe};
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::ext::ustr::UStr;
-use crate::sqlite::connection::ConnectionHandle;
+use crate::logging::QueryLogger;
+use crate::sqlite::connection::{ConnectionHandle, InterruptTimer};
 use crate::sqlite::statement::{SqliteStatement, StatementHandle};
-use crate::sqlite::{Sqlite, SqliteArguments, SqliteConnection, SqliteRow};
+use crate::sqlite::{
+    Sqlite, SqliteArguments, SqliteColumn, SqliteConnection, SqliteDone, SqliteRow,
+};
 
 fn prepare<'a>(
     conn: &mut ConnectionHandle,
@@ -58,16 +61,26 @@ fn bind(
 
 fn emplace_row_metadata(
     statement: &StatementHandle,
+    columns: &mut Vec<SqliteColumn>,
     column_names: &mut HashMap<UStr, usize>,
 ) -> Result<(), Error> {
+    columns.clear();
     column_names.clear();
 
     let num = statement.column_count();
 
+    columns.reserve(num);
     column_names.reserve(num);
 
     for i in 0..num {
         let name: UStr = statement.column_name(i).to_owned().into();
+        let type_info = statement.column_decltype(i);
+
+        columns.push(SqliteColumn {
+            ordinal: i,
+            name: name.clone(),
+            type_info,
+        });
 
         column_names.insert(name, i);
     }
@@ -81,26 +94,39 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
     fn fetch_many<'e, 'q: 'e, E: 'q>(
         self,
         mut query: E,
-    ) -> BoxStream<'e, Result<Either<u64, SqliteRow>, Error>>
+    ) -> BoxStream<'e, Result<Either<SqliteDone, SqliteRow>, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
         let s = query.query();
         let arguments = query.take_arguments();
+        let persistent = query.persistent() && arguments.is_some();
+        let timeout = query.timeout();
+        let logger = QueryLogger::new(s, arguments.as_ref(), &self.log_settings);
+
+        #[cfg(feature = "tracing")]
+        let logger = logger.span("sqlite", self.database.as_deref());
+
+        // the stream borrows the connection, so the handle outlives any use of the interrupt
+        let interrupt = self.handle.interrupt_handle();
 
-        Box::pin(try_stream! {
+        let s = try_stream! {
             let SqliteConnection {
                 handle: ref mut conn,
                 ref mut statements,
                 ref mut statement,
                 ref worker,
+                ref mut scratch_row_columns,
                 ref mut scratch_row_column_names,
                 ..
             } = self;
 
+            // interrupt the query once the timeout elapses, even while a step is blocking
+            let timer = timeout.map(|timeout| InterruptTimer::start(interrupt, timeout));
+
             // prepare statement object (or checkout from cache)
-            let mut stmt = prepare(conn, statements, statement, s, arguments.is_some())?;
+            let mut stmt = prepare(conn, statements, statement, s, persistent)?;
 
             // bind arguments, if any, to the statement
             bind(&mut stmt, arguments)?;
@@ -113,8 +139,13 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                 // the worker parks its thread on async-std when not in use
                 worker.wake();
 
+                // `sqlite3_last_insert_rowid` is the last insert on the connection; it is
+                // only reported if this statement changed it
+                let previous_rowid = handle.last_insert_rowid();
+
                 emplace_row_metadata(
                     handle,
+                    Arc::make_mut(scratch_row_columns),
                     Arc::make_mut(scratch_row_column_names),
                 )?;
 
@@ -123,9 +154,21 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                     // and send them to the still-live row object
                     SqliteRow::inflate_if_needed(handle, last_row_values.take());
 
-                    match worker.step(handle).await? {
+                    let step = worker.step(handle).await.map_err(|error| match &timer {
+                        Some(timer) if timer.fired() => Error::QueryTimedOut,
+                        _ => error,
+                    });
+
+                    match step? {
                         Either::Left(changes) => {
-                            r#yield!(Either::Left(changes));
+                            let rowid = handle.last_insert_rowid();
+
+                            let done = SqliteDone {
+                                changes,
+                                last_insert_rowid: if rowid != previous_rowid { rowid } else { 0 },
+                            };
+
+                            r#yield!(Either::Left(done));
 
                             break;
                         }
@@ -133,6 +176,7 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                         Either::Right(()) => {
                             let (row, weak_values_ref) = SqliteRow::current(
                                 *handle,
+                                scratch_row_columns,
                                 scratch_row_column_names
                             );
 
@@ -146,7 +190,9 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
             }
 
             Ok(())
-        })
+        };
+
+        Box::pin(logger.instrument(s))
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
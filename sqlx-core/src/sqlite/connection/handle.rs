@@ -1,6 +1,11 @@
 use std::ptr::NonNull;
+use std::sync::Arc;
+use std::time::Duration;
 
-use libsqlite3_sys::{sqlite3, sqlite3_close, SQLITE_OK};
+use futures_channel::oneshot;
+use futures_util::future::{self, Either};
+use libsqlite3_sys::{sqlite3, sqlite3_close, sqlite3_interrupt, SQLITE_OK};
+use parking_lot::Mutex;
 
 use crate::sqlite::SqliteError;
 
@@ -30,6 +35,93 @@ impl ConnectionHandle {
     pub(crate) fn as_ptr(&self) -> *mut sqlite3 {
         self.0.as_ptr()
     }
+
+    /// Returns a handle to interrupt the statement running on this connection.
+    ///
+    /// The interrupt handle must not be used once this handle is dropped.
+    pub(crate) fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle(self.0)
+    }
+}
+
+/// Handle to interrupt the statement running on a connection, possibly from another thread
+/// than the one stepping the statement.
+pub(crate) struct InterruptHandle(NonNull<sqlite3>);
+
+// `sqlite3_interrupt` is safe to call from any thread while the connection is open
+
+// <https://www.sqlite.org/c3ref/interrupt.html>
+
+unsafe impl Send for InterruptHandle {}
+
+impl InterruptHandle {
+    /// Makes the running statement fail with `SQLITE_INTERRUPT` as soon as possible.
+    pub(crate) fn interrupt(&self) {
+        unsafe {
+            sqlite3_interrupt(self.0.as_ptr());
+        }
+    }
+}
+
+/// Interrupts the statement running on a connection once a timeout elapses.
+///
+/// The timer runs on its own task, as the task stepping the statement may be blocked inside
+/// `sqlite3_step` (e.g. in `block_in_place` on tokio). Dropping the timer cancels it; it never
+/// touches the connection afterwards.
+pub(crate) struct InterruptTimer {
+    state: Arc<Mutex<TimerState>>,
+    _cancel: oneshot::Sender<()>,
+}
+
+struct TimerState {
+    interrupt: Option<InterruptHandle>,
+    fired: bool,
+}
+
+impl InterruptTimer {
+    pub(crate) fn start(interrupt: InterruptHandle, timeout: Duration) -> Self {
+        let state = Arc::new(Mutex::new(TimerState {
+            interrupt: Some(interrupt),
+            fired: false,
+        }));
+
+        let (cancel, cancelled) = oneshot::channel::<()>();
+
+        sqlx_rt::spawn({
+            let state = Arc::clone(&state);
+
+            async move {
+                let sleep = Box::pin(sqlx_rt::sleep(timeout));
+
+                if let Either::Left(_) = future::select(sleep, cancelled).await {
+                    // the lock is held while interrupting so the connection cannot be
+                    // closed in the meantime
+                    let mut state = state.lock();
+
+                    if let Some(interrupt) = &state.interrupt {
+                        interrupt.interrupt();
+                        state.fired = true;
+                    }
+                }
+            }
+        });
+
+        Self {
+            state,
+            _cancel: cancel,
+        }
+    }
+
+    /// Returns `true` if the timeout elapsed and the connection was interrupted.
+    pub(crate) fn fired(&self) -> bool {
+        self.state.lock().fired
+    }
+}
+
+impl Drop for InterruptTimer {
+    fn drop(&mut self) {
+        self.state.lock().interrupt = None;
+    }
 }
 
 impl Drop for ConnectionHandle {
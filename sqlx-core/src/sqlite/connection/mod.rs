@@ -7,18 +7,18 @@ use hashbrown::HashMap;
 use libsqlite3_sys::sqlite3;
 
 use crate::common::StatementCache;
-use crate::connection::{Connect, Connection};
+use crate::connection::{Connect, Connection, LogSettings};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
 use crate::sqlite::connection::establish::establish;
 use crate::sqlite::statement::{SqliteStatement, StatementWorker};
-use crate::sqlite::{Sqlite, SqliteConnectOptions};
+use crate::sqlite::{Sqlite, SqliteColumn, SqliteConnectOptions};
 
 mod establish;
 mod executor;
 mod handle;
 
-pub(crate) use handle::ConnectionHandle;
+pub(crate) use handle::{ConnectionHandle, InterruptTimer};
 
 /// A connection to a [Sqlite] database.
 pub struct SqliteConnection {
@@ -32,7 +32,15 @@ pub struct SqliteConnection {
     pub(crate) statement: Option<SqliteStatement>,
 
     // working memory for the active row's column information
+    scratch_row_columns: Arc<Vec<SqliteColumn>>,
     scratch_row_column_names: Arc<HashMap<UStr, usize>>,
+
+    // how the statements run on this connection are logged
+    pub(crate) log_settings: LogSettings,
+
+    // the name of the database, reported in the spans of statements
+    #[cfg(feature = "tracing")]
+    pub(crate) database: Option<String>,
 }
 
 impl SqliteConnection {
@@ -61,6 +69,10 @@ impl Connection for SqliteConnection {
         Box::pin(future::ok(()))
     }
 
+    fn set_logging(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+
     fn cached_statements_size(&self) -> usize {
         self.statements.len()
     }
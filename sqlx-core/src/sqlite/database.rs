@@ -1,7 +1,7 @@
 use crate::database::{Database, HasArguments, HasStatementCache, HasValueRef};
 use crate::sqlite::{
-    SqliteArgumentValue, SqliteArguments, SqliteConnection, SqliteRow, SqliteTransactionManager,
-    SqliteTypeInfo, SqliteValue, SqliteValueRef,
+    SqliteArgumentValue, SqliteArguments, SqliteColumn, SqliteConnection, SqliteDone, SqliteRow,
+    SqliteTransactionManager, SqliteTypeInfo, SqliteValue, SqliteValueRef,
 };
 
 /// Sqlite database driver.
@@ -15,6 +15,10 @@ impl Database for Sqlite {
 
     type Row = SqliteRow;
 
+    type Done = SqliteDone;
+
+    type Column = SqliteColumn;
+
     type TypeInfo = SqliteTypeInfo;
 
     type Value = SqliteValue;
@@ -0,0 +1,40 @@
+use std::iter::{Extend, IntoIterator};
+
+use crate::done::Done;
+use crate::sqlite::Sqlite;
+
+/// The result of executing a query against SQLite.
+#[derive(Debug, Default)]
+pub struct SqliteDone {
+    pub(crate) changes: u64,
+    pub(crate) last_insert_rowid: i64,
+}
+
+impl SqliteDone {
+    /// Returns the `ROWID` of the last row inserted by this query, or `0` if it inserted no
+    /// rows.
+    ///
+    /// See [`sqlite3_last_insert_rowid`](https://www.sqlite.org/c3ref/last_insert_rowid.html).
+    pub fn last_insert_rowid(&self) -> i64 {
+        self.last_insert_rowid
+    }
+}
+
+impl Done for SqliteDone {
+    type Database = Sqlite;
+
+    fn rows_affected(&self) -> u64 {
+        self.changes
+    }
+}
+
+impl Extend<SqliteDone> for SqliteDone {
+    fn extend<T: IntoIterator<Item = SqliteDone>>(&mut self, iter: T) {
+        for elem in iter {
+            self.changes += elem.changes;
+            if elem.last_insert_rowid != 0 {
+                self.last_insert_rowid = elem.last_insert_rowid;
+            }
+        }
+    }
+}
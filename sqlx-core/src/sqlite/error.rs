@@ -1,12 +1,17 @@
+use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::ffi::CStr;
 use std::fmt::{self, Display, Formatter};
 use std::os::raw::c_int;
 use std::str::from_utf8_unchecked;
 
-use libsqlite3_sys::{sqlite3, sqlite3_errmsg, sqlite3_extended_errcode};
+use libsqlite3_sys::{
+    sqlite3, sqlite3_errmsg, sqlite3_extended_errcode, SQLITE_BUSY, SQLITE_CONSTRAINT_CHECK,
+    SQLITE_CONSTRAINT_FOREIGNKEY, SQLITE_CONSTRAINT_NOTNULL, SQLITE_CONSTRAINT_PRIMARYKEY,
+    SQLITE_CONSTRAINT_UNIQUE, SQLITE_LOCKED,
+};
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, ErrorKind};
 
 // Error Codes And Messages
 // https://www.sqlite.org/c3ref/errcode.html
@@ -51,6 +56,26 @@ impl DatabaseError for SqliteError {
         &self.message
     }
 
+    /// The extended [result code](https://www.sqlite.org/rescode.html) of the error.
+    fn code(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Owned(self.code.to_string()))
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self.code {
+            SQLITE_CONSTRAINT_UNIQUE | SQLITE_CONSTRAINT_PRIMARYKEY => ErrorKind::UniqueViolation,
+            SQLITE_CONSTRAINT_FOREIGNKEY => ErrorKind::ForeignKeyViolation,
+            SQLITE_CONSTRAINT_NOTNULL => ErrorKind::NotNullViolation,
+            SQLITE_CONSTRAINT_CHECK => ErrorKind::CheckViolation,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        // the primary result code is the lower 8 bits of the extended result code
+        matches!(self.code & 0xff, SQLITE_BUSY | SQLITE_LOCKED)
+    }
+
     #[doc(hidden)]
     fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
@@ -6,8 +6,10 @@
 #![allow(unsafe_code)]
 
 mod arguments;
+mod column;
 mod connection;
 mod database;
+mod done;
 mod error;
 mod options;
 mod row;
@@ -18,8 +20,10 @@ pub mod types;
 mod value;
 
 pub use arguments::{SqliteArgumentValue, SqliteArguments};
+pub use column::SqliteColumn;
 pub use connection::SqliteConnection;
 pub use database::Sqlite;
+pub use done::SqliteDone;
 pub use error::SqliteError;
 pub use options::SqliteConnectOptions;
 pub use row::SqliteRow;
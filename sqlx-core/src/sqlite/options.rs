@@ -1,16 +1,22 @@
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
+use log::LevelFilter;
+
+use crate::connection::LogSettings;
 use crate::error::BoxDynError;
 
 // TODO: Look at go-sqlite for option ideas
 // TODO: journal_mode
 
 /// Options and flags which can be used to configure a SQLite connection.
+#[derive(Debug, Clone)]
 pub struct SqliteConnectOptions {
     pub(crate) filename: PathBuf,
     pub(crate) in_memory: bool,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) log_settings: LogSettings,
 }
 
 impl Default for SqliteConnectOptions {
@@ -25,6 +31,7 @@ impl SqliteConnectOptions {
             filename: PathBuf::from(":memory:"),
             in_memory: false,
             statement_cache_capacity: 100,
+            log_settings: LogSettings::new(),
         }
     }
 
@@ -38,6 +45,41 @@ impl SqliteConnectOptions {
         self.statement_cache_capacity = capacity;
         self
     }
+
+    /// Sets the level at which the statements run on the connection are logged.
+    ///
+    /// Statements are logged at `Debug` by default. See [`LogSettings`].
+    pub fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.log_settings = self.log_settings.log_statements(level);
+        self
+    }
+
+    /// Sets the level at which statements that ran for at least `duration` are logged.
+    ///
+    /// Statements that took a second or more are logged at `Warn` by default.
+    pub fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.log_settings = self.log_settings.log_slow_statements(level, duration);
+        self
+    }
+
+    /// Sets whether the values bound to a statement are logged along with it.
+    ///
+    /// Values are not logged by default. See [`LogSettings::log_arguments`].
+    pub fn log_arguments(mut self, log: bool) -> Self {
+        self.log_settings = self.log_settings.log_arguments(log);
+        self
+    }
+
+    /// Sets a function that returns the text to log in place of each bound value.
+    ///
+    /// See [`LogSettings::redact_arguments`].
+    pub fn redact_arguments<F>(mut self, redact: F) -> Self
+    where
+        F: Fn(usize, &str) -> String + 'static + Send + Sync,
+    {
+        self.log_settings = self.log_settings.redact_arguments(redact);
+        self
+    }
 }
 
 impl FromStr for SqliteConnectOptions {
@@ -48,6 +90,7 @@ impl FromStr for SqliteConnectOptions {
             filename: PathBuf::new(),
             in_memory: false,
             statement_cache_capacity: 100,
+            log_settings: LogSettings::new(),
         };
 
         // remove scheme
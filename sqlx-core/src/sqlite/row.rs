@@ -9,7 +9,7 @@ use crate::error::Error;
 use crate::ext::ustr::UStr;
 use crate::row::{ColumnIndex, Row};
 use crate::sqlite::statement::StatementHandle;
-use crate::sqlite::{Sqlite, SqliteValue, SqliteValueRef};
+use crate::sqlite::{Sqlite, SqliteColumn, SqliteValue, SqliteValueRef};
 
 /// Implementation of [`Row`] for SQLite.
 pub struct SqliteRow {
@@ -25,6 +25,7 @@ pub struct SqliteRow {
     pub(crate) values: Arc<AtomicPtr<SqliteValue>>,
     pub(crate) num_values: usize,
 
+    pub(crate) columns: Arc<Vec<SqliteColumn>>,
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
 }
 
@@ -45,6 +46,7 @@ impl SqliteRow {
     // to increment the statement with [step]
     pub(crate) fn current(
         statement: StatementHandle,
+        columns: &Arc<Vec<SqliteColumn>>,
         column_names: &Arc<HashMap<UStr, usize>>,
     ) -> (Self, Weak<AtomicPtr<SqliteValue>>) {
         let values = Arc::new(AtomicPtr::new(null_mut()));
@@ -55,6 +57,7 @@ impl SqliteRow {
             statement,
             values,
             num_values: size,
+            columns: Arc::clone(columns),
             column_names: Arc::clone(column_names),
         };
 
@@ -95,6 +98,10 @@ impl Row for SqliteRow {
         self.num_values
     }
 
+    fn columns(&self) -> &[SqliteColumn] {
+        &self.columns
+    }
+
     fn try_get_raw<I>(&self, index: I) -> Result<SqliteValueRef<'_>, Error>
     where
         I: ColumnIndex<Self>,
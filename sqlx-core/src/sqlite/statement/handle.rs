@@ -11,8 +11,8 @@ use libsqlite3_sys::{
     sqlite3_column_count, sqlite3_column_database_name, sqlite3_column_decltype,
     sqlite3_column_double, sqlite3_column_int, sqlite3_column_int64, sqlite3_column_name,
     sqlite3_column_origin_name, sqlite3_column_table_name, sqlite3_column_type,
-    sqlite3_column_value, sqlite3_db_handle, sqlite3_stmt, sqlite3_table_column_metadata,
-    SQLITE_OK, SQLITE_TRANSIENT, SQLITE_UTF8,
+    sqlite3_column_value, sqlite3_db_handle, sqlite3_last_insert_rowid, sqlite3_stmt,
+    sqlite3_stmt_readonly, sqlite3_table_column_metadata, SQLITE_OK, SQLITE_TRANSIENT, SQLITE_UTF8,
 };
 
 use crate::error::{BoxDynError, Error};
@@ -51,12 +51,26 @@ impl StatementHandle {
 
     #[inline]
     pub(crate) fn changes(&self) -> u64 {
+        // a statement that cannot write, such as a SELECT, changed no rows
+        // https://sqlite.org/c3ref/stmt_readonly.html
+        if unsafe { sqlite3_stmt_readonly(self.0.as_ptr()) } != 0 {
+            return 0;
+        }
+
         // returns the number of changes of the *last* statement; not
         // necessarily this statement.
         // https://sqlite.org/c3ref/changes.html
         unsafe { sqlite3_changes(self.db_handle()) as u64 }
     }
 
+    #[inline]
+    pub(crate) fn last_insert_rowid(&self) -> i64 {
+        // returns the rowid of the most recent successful INSERT on the
+        // database connection; not necessarily from this statement.
+        // https://sqlite.org/c3ref/last_insert_rowid.html
+        unsafe { sqlite3_last_insert_rowid(self.db_handle()) }
+    }
+
     #[inline]
     pub(crate) fn column_name(&self, index: usize) -> &str {
         // https://sqlite.org/c3ref/column_name.html
@@ -1,14 +1,14 @@
 use std::ptr;
 
 use futures_core::future::BoxFuture;
-use libsqlite3_sys::{sqlite3_exec, SQLITE_OK};
+use libsqlite3_sys::{sqlite3_exec, SQLITE_BUSY, SQLITE_OK};
 
-use crate::error::Error;
+use crate::error::{DatabaseError, Error};
 use crate::executor::Executor;
 use crate::sqlite::{Sqlite, SqliteConnection, SqliteError};
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    TransactionManager, TransactionOptions,
 };
 
 /// Implementation of [`TransactionManager`] for SQLite.
@@ -17,7 +17,12 @@ pub struct SqliteTransactionManager;
 impl TransactionManager for SqliteTransactionManager {
     type Database = Sqlite;
 
-    fn begin(conn: &mut SqliteConnection, depth: usize) -> BoxFuture<'_, Result<(), Error>> {
+    fn begin(
+        conn: &mut SqliteConnection,
+        depth: usize,
+        _options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        // SQLite transactions are always serializable and do not have an access mode
         Box::pin(async move {
             conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
 
@@ -65,4 +70,12 @@ impl TransactionManager for SqliteTransactionManager {
             }
         }
     }
+
+    fn is_retryable(error: &dyn DatabaseError) -> bool {
+        // the primary result code is the lower 8 bits of the extended result code
+        match error.code().and_then(|code| code.parse::<i32>().ok()) {
+            Some(code) => code & 0xff == SQLITE_BUSY,
+            None => false,
+        }
+    }
 }
@@ -0,0 +1,89 @@
+//! Types for working with explicitly prepared statements.
+
+use crate::column::Column;
+use crate::database::{Database, HasArguments};
+use crate::describe::Describe;
+use crate::from_row::FromRow;
+use crate::query::{query, Query};
+use crate::query_as::{query_as, QueryAs};
+use crate::query_scalar::{query_scalar, QueryScalar};
+
+/// A SQL string that has been checked and described by the database.
+///
+/// Returned by [`Executor::prepare`](crate::executor::Executor::prepare). Preparing a statement
+/// makes the database parse and check it, which surfaces syntax errors and references to
+/// missing tables or columns immediately, and exposes the expected parameters and the
+/// columns of the result.
+///
+/// This does not hold on to a statement on the database. It can be executed any number of
+/// times with [`query`](#method.query) and its siblings, on any connection; such queries go
+/// through the statement cache of the connection they are executed on, as with any other
+/// query.
+#[derive(Debug)]
+pub struct Statement<'q, DB: Database> {
+    pub(crate) sql: &'q str,
+    pub(crate) parameters: Vec<Option<DB::TypeInfo>>,
+    pub(crate) columns: Vec<DB::Column>,
+}
+
+impl<'q, DB: Database> Statement<'q, DB> {
+    pub(crate) fn new(sql: &'q str, describe: Describe<DB>) -> Self {
+        Self {
+            sql,
+            parameters: describe.params,
+            columns: describe
+                .columns
+                .into_iter()
+                .enumerate()
+                .map(|(ordinal, column)| DB::Column::from_describe(ordinal, column))
+                .collect(),
+        }
+    }
+
+    /// Returns the SQL of this statement.
+    pub fn sql(&self) -> &'q str {
+        self.sql
+    }
+
+    /// Returns the expected parameters of this statement.
+    ///
+    /// The number of parameters is always known. The type of each parameter is currently only
+    /// inferred by PostgreSQL and is `None` on all other database drivers.
+    pub fn parameters(&self) -> &[Option<DB::TypeInfo>] {
+        &self.parameters
+    }
+
+    /// Returns the columns that will be found in the results of this statement.
+    ///
+    /// Only the name and type of each column are described; details such as the table that a
+    /// PostgreSQL column comes from are not available.
+    pub fn columns(&self) -> &[DB::Column] {
+        &self.columns
+    }
+
+    /// Make a query from this statement, to bind arguments to and execute.
+    #[inline]
+    pub fn query(&self) -> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
+        query(self.sql)
+    }
+
+    /// Make a query from this statement that is mapped to a concrete type
+    /// using [`FromRow`](crate::row::FromRow).
+    #[inline]
+    pub fn query_as<O>(&self) -> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments>
+    where
+        O: for<'r> FromRow<'r, DB::Row>,
+    {
+        query_as(self.sql)
+    }
+
+    /// Make a query from this statement that is mapped to a single concrete type
+    /// using [`FromRow`](crate::row::FromRow).
+    #[inline]
+    pub fn query_scalar<O>(&self) -> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Arguments>
+    where
+        (O,): for<'r> FromRow<'r, DB::Row>,
+    {
+        query_scalar(self.sql)
+    }
+}
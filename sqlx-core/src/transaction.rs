@@ -5,9 +5,9 @@ use std::ops::{Deref, DerefMut};
 use futures_core::future::BoxFuture;
 use futures_util::{future, FutureExt};
 
-use crate::connection::Connection;
+use crate::connection::{Connection, LogSettings};
 use crate::database::Database;
-use crate::error::Error;
+use crate::error::{DatabaseError, Error, ErrorKind};
 use crate::ext::maybe_owned::MaybeOwned;
 
 /// Generic management of database transactions.
@@ -18,9 +18,12 @@ pub trait TransactionManager {
     type Database: Database;
 
     /// Begin a new transaction or establish a savepoint within the active transaction.
+    ///
+    /// The `options` only apply when beginning a new transaction (a `depth` of zero).
     fn begin(
         conn: &mut <Self::Database as Database>::Connection,
         depth: usize,
+        options: TransactionOptions,
     ) -> BoxFuture<'_, Result<(), Error>>;
 
     /// Commit the active transaction or release the most recent savepoint.
@@ -37,11 +40,99 @@ pub trait TransactionManager {
 
     /// Starts to abort the active transaction or restore from the most recent snapshot.
     fn start_rollback(conn: &mut <Self::Database as Database>::Connection, depth: usize);
+
+    /// Returns `true` if the error indicates that the transaction failed due to a conflict
+    /// with a concurrent transaction, such as a serialization failure or a deadlock, and
+    /// is expected to succeed if the transaction is run again.
+    fn is_retryable(error: &dyn DatabaseError) -> bool {
+        matches!(
+            error.kind(),
+            ErrorKind::SerializationFailure | ErrorKind::Deadlock
+        )
+    }
+}
+
+/// The isolation level of a transaction.
+///
+/// See the documentation of the database for the exact guarantees of each level. SQLite
+/// transactions are always serializable and ignore the requested level.
+///
+/// In MSSQL the isolation level applies to the whole session; the level the session had before
+/// the transaction is restored when it is committed or rolled back.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// The access mode of a transaction.
+///
+/// Supported by PostgreSQL and MySQL; ignored by SQLite and MSSQL.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AccessMode {
+    ReadWrite,
+    ReadOnly,
+}
+
+impl AccessMode {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            AccessMode::ReadWrite => "READ WRITE",
+            AccessMode::ReadOnly => "READ ONLY",
+        }
+    }
+}
+
+/// Options for beginning a new transaction with [`Connection::begin_with`] or
+/// [`Pool::begin_with`].
+///
+/// Options that are left as `None` use the default of the database (or of the session).
+/// The options are ignored when establishing a savepoint within an active transaction.
+///
+/// ```rust,ignore
+/// let mut tx = conn
+///     .begin_with(TransactionOptions {
+///         isolation: Some(IsolationLevel::Serializable),
+///         access_mode: Some(AccessMode::ReadOnly),
+///         deferrable: true,
+///     })
+///     .await?;
+/// ```
+///
+/// [`Connection::begin_with`]: crate::connection::Connection::begin_with
+/// [`Pool::begin_with`]: crate::pool::Pool::begin_with
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct TransactionOptions {
+    /// The isolation level of the transaction.
+    pub isolation: Option<IsolationLevel>,
+
+    /// Whether the transaction may write to the database.
+    pub access_mode: Option<AccessMode>,
+
+    /// Whether a `SERIALIZABLE READ ONLY` transaction may block when it starts until it can
+    /// run without risk of a serialization failure.
+    ///
+    /// Only supported by PostgreSQL; ignored by other databases.
+    pub deferrable: bool,
 }
 
 /// An in-progress database transaction or savepoint.
 ///
-/// A transaction starts with a call to [`Pool::begin`] or [`Connection::begin`].
+/// A transaction starts with a call to [`Pool::begin`] or [`Connection::begin`], or their
+/// `begin_with` counterparts that accept [`TransactionOptions`].
 ///
 /// A transaction should end with a call to [`commit`] or [`rollback`]. If neither are called
 /// before the transaction goes out-of-scope, [`rollback`] is called. In other
@@ -74,13 +165,21 @@ where
     DB: Database,
     C: Sized + Connection<Database = DB>,
 {
-    pub(crate) fn begin(conn: impl Into<MaybeOwned<'c, C>>) -> BoxFuture<'c, Result<Self, Error>> {
+    pub(crate) fn begin(
+        conn: impl Into<MaybeOwned<'c, C>>,
+        options: TransactionOptions,
+    ) -> BoxFuture<'c, Result<Self, Error>> {
         let mut conn = conn.into();
 
         Box::pin(async move {
             let depth = conn.transaction_depth();
 
-            DB::TransactionManager::begin(conn.get_mut(), depth).await?;
+            instrument!(
+                DB::TransactionManager::begin(conn.get_mut(), depth, options),
+                "sqlx.transaction.begin",
+                depth = depth + 1
+            )
+            .await?;
 
             Ok(Self {
                 depth: depth + 1,
@@ -92,14 +191,24 @@ where
 
     /// Commits this transaction or savepoint.
     pub async fn commit(mut self) -> Result<(), Error> {
-        DB::TransactionManager::commit(self.connection.get_mut(), self.depth).await?;
+        instrument!(
+            DB::TransactionManager::commit(self.connection.get_mut(), self.depth),
+            "sqlx.transaction.commit",
+            depth = self.depth
+        )
+        .await?;
         self.open = false;
         Ok(())
     }
 
     /// Aborts this transaction or savepoint.
     pub async fn rollback(mut self) -> Result<(), Error> {
-        DB::TransactionManager::rollback(self.connection.get_mut(), self.depth).await?;
+        instrument!(
+            DB::TransactionManager::rollback(self.connection.get_mut(), self.depth),
+            "sqlx.transaction.rollback",
+            depth = self.depth
+        )
+        .await?;
         self.open = false;
         Ok(())
     }
@@ -122,6 +231,10 @@ where
         self.connection.ping()
     }
 
+    fn set_logging(&mut self, settings: LogSettings) {
+        self.get_mut().set_logging(settings)
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.get_mut().flush()
@@ -164,7 +277,10 @@ macro_rules! impl_executor_for_transaction {
                 query: E,
             ) -> futures_core::stream::BoxStream<
                 'e,
-                Result<either::Either<u64, $Row>, crate::error::Error>,
+                Result<
+                    either::Either<<$DB as crate::database::Database>::Done, $Row>,
+                    crate::error::Error,
+                >,
             >
             where
                 't: 'e,
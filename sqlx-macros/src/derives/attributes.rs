@@ -1,7 +1,7 @@
 use proc_macro2::Ident;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
-use syn::{Attribute, DeriveInput, Field, Lit, Meta, MetaNameValue, NestedMeta, Variant};
+use syn::{Attribute, DeriveInput, Field, Lit, Meta, MetaNameValue, NestedMeta, Type, Variant};
 
 macro_rules! assert_attribute {
     ($e:expr, $err:expr, $input:expr) => {
@@ -31,6 +31,10 @@ pub enum RenameAll {
     LowerCase,
     SnakeCase,
     UpperCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    CamelCase,
+    PascalCase,
 }
 
 pub struct SqlxContainerAttributes {
@@ -42,6 +46,10 @@ pub struct SqlxContainerAttributes {
 
 pub struct SqlxChildAttributes {
     pub rename: Option<String>,
+    pub default: bool,
+    pub flatten: bool,
+    pub skip: bool,
+    pub try_from: Option<Type>,
 }
 
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
@@ -72,6 +80,10 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                     "lowercase" => RenameAll::LowerCase,
                                     "snake_case" => RenameAll::SnakeCase,
                                     "uppercase" => RenameAll::UpperCase,
+                                    "SCREAMING_SNAKE_CASE" => RenameAll::ScreamingSnakeCase,
+                                    "kebab-case" => RenameAll::KebabCase,
+                                    "camelCase" => RenameAll::CamelCase,
+                                    "PascalCase" => RenameAll::PascalCase,
 
                                     _ => fail!(meta, "unexpected value for rename_all"),
                                 };
@@ -116,6 +128,10 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
 
 pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttributes> {
     let mut rename = None;
+    let mut default = None;
+    let mut flatten = None;
+    let mut skip = None;
+    let mut try_from = None;
 
     for attr in input {
         let meta = attr
@@ -133,6 +149,24 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                                 ..
                             }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("try_from") => {
+                                try_set!(try_from, val.parse::<Type>()?, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("default") => {
+                                try_set!(default, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("flatten") => {
+                                try_set!(flatten, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("skip") => try_set!(skip, true, value),
+
                             u => fail!(u, "unexpected attribute"),
                         },
                         u => fail!(u, "unexpected attribute"),
@@ -143,7 +177,13 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
         }
     }
 
-    Ok(SqlxChildAttributes { rename })
+    Ok(SqlxChildAttributes {
+        rename,
+        default: default.unwrap_or(false),
+        flatten: flatten.unwrap_or(false),
+        skip: skip.unwrap_or(false),
+        try_from,
+    })
 }
 
 pub fn check_transparent_attributes(
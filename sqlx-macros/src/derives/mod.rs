@@ -1,3 +1,4 @@
+#[macro_use]
 mod attributes;
 mod decode;
 mod encode;
@@ -10,7 +11,7 @@ pub(crate) use r#type::expand_derive_type;
 pub(crate) use row::expand_derive_from_row;
 
 use self::attributes::RenameAll;
-use heck::SnakeCase;
+use heck::{CamelCase, KebabCase, MixedCase, ShoutySnakeCase, SnakeCase};
 use std::iter::FromIterator;
 use syn::DeriveInput;
 
@@ -33,5 +34,9 @@ pub(crate) fn rename_all(s: &str, pattern: RenameAll) -> String {
         RenameAll::LowerCase => s.to_lowercase(),
         RenameAll::SnakeCase => s.to_snake_case(),
         RenameAll::UpperCase => s.to_uppercase(),
+        RenameAll::ScreamingSnakeCase => s.to_shouty_snake_case(),
+        RenameAll::KebabCase => s.to_kebab_case(),
+        RenameAll::CamelCase => s.to_mixed_case(),
+        RenameAll::PascalCase => s.to_camel_case(),
     }
 }
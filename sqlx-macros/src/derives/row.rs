@@ -1,11 +1,12 @@
 use proc_macro2::Span;
 use quote::quote;
 use syn::{
-    parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Field,
-    Fields, FieldsNamed, Lifetime, Stmt,
+    parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Expr, Field,
+    Fields, FieldsNamed, FieldsUnnamed, Lifetime, Stmt,
 };
 
-use super::attributes::parse_child_attributes;
+use super::attributes::{parse_child_attributes, parse_container_attributes};
+use super::rename_all;
 
 pub fn expand_derive_from_row(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     match &input.data {
@@ -15,12 +16,9 @@ pub fn expand_derive_from_row(input: &DeriveInput) -> syn::Result<proc_macro2::T
         }) => expand_derive_from_row_struct(input, named),
 
         Data::Struct(DataStruct {
-            fields: Fields::Unnamed(_),
+            fields: Fields::Unnamed(FieldsUnnamed { unnamed, .. }),
             ..
-        }) => Err(syn::Error::new_spanned(
-            input,
-            "tuple structs are not supported",
-        )),
+        }) => expand_derive_from_row_struct_unnamed(input, unnamed),
 
         Data::Struct(DataStruct {
             fields: Fields::Unit,
@@ -59,32 +57,127 @@ fn expand_derive_from_row_struct(
         generics.params.insert(0, parse_quote!(#lifetime));
     }
 
+    let container_attributes = parse_container_attributes(&input.attrs)?;
+
+    assert_attribute!(
+        !container_attributes.transparent,
+        "unexpected #[sqlx(transparent)]",
+        input
+    );
+
+    assert_attribute!(
+        container_attributes.rename.is_none(),
+        "unexpected #[sqlx(rename = ..)]",
+        input
+    );
+
     let predicates = &mut generics.make_where_clause().predicates;
 
     predicates.push(parse_quote!(&#lifetime str: sqlx::ColumnIndex<R>));
 
+    let mut reads: Vec<Stmt> = Vec::with_capacity(fields.len());
+
     for field in fields {
+        let id = &field.ident;
         let ty = &field.ty;
+        let attributes = parse_child_attributes(&field.attrs)?;
 
-        predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
-        predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
-    }
+        if attributes.skip {
+            assert_attribute!(
+                attributes.rename.is_none()
+                    && attributes.try_from.is_none()
+                    && !attributes.default
+                    && !attributes.flatten,
+                "#[sqlx(skip)] cannot be combined with other attributes",
+                field
+            );
 
-    let (impl_generics, _, where_clause) = generics.split_for_impl();
+            predicates.push(parse_quote!(#ty: ::std::default::Default));
+
+            reads.push(parse_quote!(
+                let #id: #ty = ::std::default::Default::default();
+            ));
+
+            continue;
+        }
+
+        if attributes.flatten {
+            assert_attribute!(
+                attributes.rename.is_none() && attributes.try_from.is_none() && !attributes.default,
+                "#[sqlx(flatten)] cannot be combined with other attributes",
+                field
+            );
+
+            predicates.push(parse_quote!(#ty: sqlx::FromRow<#lifetime, R>));
+
+            reads.push(parse_quote!(
+                let #id: #ty = <#ty as sqlx::FromRow<#lifetime, R>>::from_row(row)?;
+            ));
+
+            continue;
+        }
+
+        let id_s = match (attributes.rename, container_attributes.rename_all) {
+            (Some(rename), _) => rename,
+            (None, pattern) => {
+                let name = id.as_ref().unwrap().to_string();
+                let name = name.trim_start_matches("r#");
 
-    let reads = fields.iter().filter_map(|field| -> Option<Stmt> {
-        let id = &field.ident.as_ref()?;
-        let attributes = parse_child_attributes(&field.attrs).unwrap();
-        let id_s = match attributes.rename {
-            Some(rename) => rename,
-            None => id.to_string().trim_start_matches("r#").to_owned(),
+                match pattern {
+                    Some(pattern) => rename_all(name, pattern),
+                    None => name.to_owned(),
+                }
+            }
         };
-        let ty = &field.ty;
 
-        Some(parse_quote!(
-            let #id: #ty = row.try_get(#id_s)?;
-        ))
-    });
+        let expr: Expr = match &attributes.try_from {
+            Some(try_from) => {
+                predicates
+                    .push(parse_quote!(#try_from: sqlx::decode::Decode<#lifetime, R::Database>));
+                predicates.push(parse_quote!(#try_from: sqlx::types::Type<R::Database>));
+                predicates.push(parse_quote!(#ty: ::std::convert::TryFrom<#try_from>));
+                predicates.push(parse_quote!(
+                    <#ty as ::std::convert::TryFrom<#try_from>>::Error:
+                        ::std::error::Error + ::std::marker::Send + ::std::marker::Sync + 'static
+                ));
+
+                parse_quote!(
+                    row.try_get::<#try_from, _>(#id_s).and_then(|v| {
+                        <#ty as ::std::convert::TryFrom<#try_from>>::try_from(v).map_err(|e| {
+                            sqlx::Error::ColumnDecode {
+                                index: #id_s.into(),
+                                source: e.into(),
+                            }
+                        })
+                    })
+                )
+            }
+
+            None => {
+                predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
+                predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
+
+                parse_quote!(row.try_get(#id_s))
+            }
+        };
+
+        if attributes.default {
+            predicates.push(parse_quote!(#ty: ::std::default::Default));
+
+            reads.push(parse_quote!(
+                let #id: #ty = match #expr {
+                    Err(sqlx::Error::ColumnNotFound(_)) => ::std::default::Default::default(),
+                    res => res?,
+                };
+            ));
+        } else {
+            reads.push(parse_quote!(
+                let #id: #ty = #expr?;
+            ));
+        }
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
     let names = fields.iter().map(|field| &field.ident);
 
@@ -100,3 +193,72 @@ fn expand_derive_from_row_struct(
         }
     ))
 }
+
+fn expand_derive_from_row_struct_unnamed(
+    input: &DeriveInput,
+    fields: &Punctuated<Field, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+
+    let generics = &input.generics;
+
+    let (lifetime, provided) = generics
+        .lifetimes()
+        .next()
+        .map(|def| (def.lifetime.clone(), false))
+        .unwrap_or_else(|| (Lifetime::new("'a", Span::call_site()), true));
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote!(R: sqlx::Row));
+
+    if provided {
+        generics.params.insert(0, parse_quote!(#lifetime));
+    }
+
+    let container_attributes = parse_container_attributes(&input.attrs)?;
+
+    assert_attribute!(
+        !container_attributes.transparent
+            && container_attributes.rename.is_none()
+            && container_attributes.rename_all.is_none(),
+        "unexpected attribute on a tuple struct; columns are read by position",
+        input
+    );
+
+    let predicates = &mut generics.make_where_clause().predicates;
+
+    for field in fields {
+        let attributes = parse_child_attributes(&field.attrs)?;
+
+        assert_attribute!(
+            attributes.rename.is_none()
+                && attributes.try_from.is_none()
+                && !attributes.default
+                && !attributes.flatten
+                && !attributes.skip,
+            "unexpected attribute on a tuple struct field; columns are read by position",
+            field
+        );
+
+        let ty = &field.ty;
+
+        predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
+        predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let gets = (0..fields.len()).map(|idx| quote!(row.try_get(#idx)?));
+
+    Ok(quote!(
+        impl #impl_generics sqlx::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
+            fn from_row(row: &#lifetime R) -> sqlx::Result<Self> {
+                Ok(#ident (
+                    #(#gets),*
+                ))
+            }
+        }
+    ))
+}
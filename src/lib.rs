@@ -1,16 +1,22 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-pub use sqlx_core::arguments::{Arguments, IntoArguments};
-pub use sqlx_core::connection::{Connect, Connection};
+pub use sqlx_core::arguments::{Argument, Arguments, IntoArguments};
+pub use sqlx_core::column::Column;
+pub use sqlx_core::connection::{Connect, Connection, LogSettings};
 pub use sqlx_core::database::{self, Database};
+pub use sqlx_core::done::Done;
 pub use sqlx_core::executor::{Execute, Executor};
 pub use sqlx_core::from_row::FromRow;
 pub use sqlx_core::pool::{self, Pool};
 pub use sqlx_core::query::{query, query_with};
 pub use sqlx_core::query_as::{query_as, query_as_with};
+pub use sqlx_core::query_builder::{self, QueryBuilder};
 pub use sqlx_core::query_scalar::{query_scalar, query_scalar_with};
 pub use sqlx_core::row::{ColumnIndex, Row};
-pub use sqlx_core::transaction::{Transaction, TransactionManager};
+pub use sqlx_core::statement::Statement;
+pub use sqlx_core::transaction::{
+    AccessMode, IsolationLevel, Transaction, TransactionManager, TransactionOptions,
+};
 pub use sqlx_core::type_info::TypeInfo;
 pub use sqlx_core::types::Type;
 pub use sqlx_core::value::{Value, ValueRef};
@@ -21,6 +27,10 @@ pub use sqlx_core::describe;
 #[doc(inline)]
 pub use sqlx_core::error::{self, Error, Result};
 
+#[cfg(feature = "any")]
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub use sqlx_core::any::{self, Any, AnyConnection, AnyPool};
+
 #[cfg(feature = "mysql")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
 pub use sqlx_core::mysql::{self, MySql, MySqlConnection, MySqlPool};
@@ -110,6 +120,7 @@ pub mod query {
 pub mod prelude {
     pub use super::Connect;
     pub use super::Connection;
+    pub use super::Done;
     pub use super::Executor;
     pub use super::FromRow;
     pub use super::IntoArguments;
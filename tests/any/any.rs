@@ -0,0 +1,215 @@
+use futures::TryStreamExt;
+use sqlx::any::{AnyArguments, AnyConnectOptions, AnyKind};
+use sqlx::{Any, AnyConnection, Connect, Connection, Done, Executor, QueryBuilder, Row};
+use sqlx_test::new;
+
+// the placeholder syntax for the first bind parameter of the database in use
+fn param(kind: AnyKind) -> &'static str {
+    match kind {
+        #[cfg(feature = "postgres")]
+        AnyKind::Postgres => "$1",
+
+        #[cfg(feature = "mysql")]
+        AnyKind::MySql => "?",
+
+        #[cfg(feature = "sqlite")]
+        AnyKind::Sqlite => "?",
+
+        #[cfg(feature = "mssql")]
+        AnyKind::Mssql => "@p1",
+    }
+}
+
+#[sqlx_macros::test]
+async fn it_connects() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    conn.ping().await?;
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_parses_the_kind_from_the_url() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let options: AnyConnectOptions = url.parse().map_err(sqlx::Error::ParseConnectOptions)?;
+    let conn = AnyConnection::connect_with(&options).await?;
+
+    assert_eq!(conn.kind(), options.kind());
+    assert_eq!(conn.kind(), url.parse::<AnyKind>()?);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_rejects_an_unknown_scheme() -> anyhow::Result<()> {
+    let res = AnyConnection::connect("foo://localhost/sqlx").await;
+
+    assert!(matches!(res, Err(sqlx::Error::ParseConnectOptions(_))));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_and_decodes_by_index_and_name() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let row = conn.fetch_one("SELECT 'Hello World' AS greeting").await?;
+
+    assert_eq!(row.len(), 1);
+    assert_eq!(row.try_get::<String, _>(0)?, "Hello World");
+    assert_eq!(row.try_get::<String, _>("greeting")?, "Hello World");
+    assert!(matches!(
+        row.try_get::<String, _>("missing"),
+        Err(sqlx::Error::ColumnNotFound(_))
+    ));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_and_decodes_values() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+    let sql = format!("SELECT {}", param(conn.kind()));
+
+    let v: String = sqlx::query_scalar(&sql)
+        .bind("Hello World")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(v, "Hello World");
+
+    let v: String = sqlx::query_scalar(&sql)
+        .bind(String::from("Goodbye"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(v, "Goodbye");
+
+    let v: Option<String> = sqlx::query_scalar(&sql)
+        .bind(Option::<String>::None)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(v, None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_builds_queries_for_the_kind_of_the_connection() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let mut builder =
+        QueryBuilder::<Any>::with_arguments("SELECT ", AnyArguments::new(conn.kind()));
+
+    builder.push_bind("Hello World");
+
+    let row = builder.build().fetch_one(&mut conn).await?;
+    assert_eq!(row.try_get::<String, _>(0)?, "Hello World");
+
+    // a query built without the kind writes `?`, which is rejected where that is not the syntax
+    let mut builder = QueryBuilder::<Any>::new("SELECT ");
+    builder.push_bind("Hello World");
+
+    let res = builder.build().fetch_one(&mut conn).await;
+
+    if param(conn.kind()) == "?" {
+        assert!(res.is_ok());
+    } else {
+        assert!(matches!(res, Err(sqlx::Error::InvalidArgument(_))));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn it_returns_the_last_insert_id_only_after_an_insert() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    if conn.kind() != AnyKind::Sqlite {
+        return Ok(());
+    }
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let done = conn.execute("INSERT INTO users (id) VALUES (10)").await?;
+    assert_eq!(done.last_insert_id(), Some(10));
+
+    // the ROWID of the previous insert is not reported for statements that insert no rows
+    let done = conn.execute("SELECT id FROM users").await?;
+    assert_eq!(done.last_insert_id(), None);
+
+    let done = conn
+        .execute("UPDATE users SET id = 12 WHERE id = 11")
+        .await?;
+    assert_eq!(done.last_insert_id(), None);
+
+    // nor for statements that change rows without inserting any
+    let done = conn
+        .execute("UPDATE users SET id = 11 WHERE id = 10")
+        .await?;
+    assert_eq!(done.rows_affected(), 1);
+    assert_eq!(done.last_insert_id(), None);
+
+    let done = conn.execute("DELETE FROM users").await?;
+    assert_eq!(done.rows_affected(), 1);
+    assert_eq!(done.last_insert_id(), None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_streams_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let mut s = conn.fetch("SELECT 'a' UNION ALL SELECT 'b' UNION ALL SELECT 'c'");
+    let mut values = Vec::new();
+
+    while let Some(row) = s.try_next().await? {
+        values.push(row.try_get::<String, _>(0)?);
+    }
+
+    assert_eq!(values, vec!["a", "b", "c"]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_with_pool() -> anyhow::Result<()> {
+    let pool = sqlx_test::pool::<Any>().await?;
+
+    let rows = pool.fetch_all("SELECT 'x' UNION ALL SELECT 'y'").await?;
+    assert_eq!(rows.len(), 2);
+
+    let mut conn = pool.acquire().await?;
+    let row = conn.fetch_one("SELECT 'z'").await?;
+    assert_eq!(row.try_get::<String, _>(0)?, "z");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_work_with_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    {
+        let mut tx = conn.begin().await?;
+        let row = tx.fetch_one("SELECT 'in a transaction'").await?;
+        assert_eq!(row.try_get::<String, _>(0)?, "in a transaction");
+        tx.rollback().await?;
+    }
+
+    {
+        let mut tx = conn.begin().await?;
+        tx.execute("SELECT 1").await?;
+        tx.commit().await?;
+    }
+
+    conn.ping().await?;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use futures::TryStreamExt;
 use sqlx::mssql::Mssql;
-use sqlx::{Connection, Executor, Row};
+use sqlx::{Connection, Done, Executor, IsolationLevel, Row, TransactionOptions};
 use sqlx_core::mssql::MssqlRow;
 use sqlx_test::new;
 
@@ -55,12 +55,12 @@ CREATE TABLE #users (id INTEGER PRIMARY KEY);
         .await?;
 
     for index in 1..=10_i32 {
-        let cnt = sqlx::query("INSERT INTO #users (id) VALUES (@p1)")
+        let done = sqlx::query("INSERT INTO #users (id) VALUES (@p1)")
             .bind(index * 2)
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(done.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query("SELECT id FROM #users")
@@ -221,3 +221,76 @@ async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    let mut conn = new::<Mssql>().await?;
+
+    // 2 is READ COMMITTED and 4 is SERIALIZABLE
+    let isolation_sql =
+        "SELECT transaction_isolation_level FROM sys.dm_exec_sessions WHERE session_id = @@SPID";
+
+    let mut tx = conn
+        .begin_with(TransactionOptions {
+            isolation: Some(IsolationLevel::Serializable),
+            ..Default::default()
+        })
+        .await?;
+
+    let isolation: i16 = sqlx::query_scalar(isolation_sql).fetch_one(&mut tx).await?;
+    assert_eq!(isolation, 4);
+
+    tx.commit().await?;
+
+    // the options do not leak into statements or transactions that follow
+    let isolation: i16 = sqlx::query_scalar(isolation_sql)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(isolation, 2);
+
+    let mut tx = conn.begin().await?;
+
+    let isolation: i16 = sqlx::query_scalar(isolation_sql).fetch_one(&mut tx).await?;
+    assert_eq!(isolation, 2);
+
+    tx.rollback().await?;
+
+    // nor after a rollback
+    let tx = conn
+        .begin_with(TransactionOptions {
+            isolation: Some(IsolationLevel::Serializable),
+            ..Default::default()
+        })
+        .await?;
+
+    tx.rollback().await?;
+
+    let isolation: i16 = sqlx::query_scalar(isolation_sql)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(isolation, 2);
+
+    // the level of the session before the transaction is restored, not the default
+    conn.execute("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+        .await?;
+
+    let tx = conn
+        .begin_with(TransactionOptions {
+            isolation: Some(IsolationLevel::Serializable),
+            ..Default::default()
+        })
+        .await?;
+
+    tx.commit().await?;
+
+    let isolation: i16 = sqlx::query_scalar(isolation_sql)
+        .fetch_one(&mut conn)
+        .await?;
+
+    // 3 is REPEATABLE READ
+    assert_eq!(isolation, 3);
+
+    Ok(())
+}
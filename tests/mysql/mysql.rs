@@ -1,6 +1,8 @@
 use futures::TryStreamExt;
 use sqlx::mysql::{MySql, MySqlPool, MySqlRow};
-use sqlx::{Connection, Executor, Row};
+use sqlx::{
+    AccessMode, Column, Connection, Done, Executor, IsolationLevel, Row, TransactionOptions,
+};
 use sqlx_test::new;
 
 #[sqlx_macros::test]
@@ -55,12 +57,12 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);
         .await?;
 
     for index in 1..=10_i32 {
-        let cnt = sqlx::query("INSERT INTO users (id) VALUES (?)")
+        let done = sqlx::query("INSERT INTO users (id) VALUES (?)")
             .bind(index)
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(done.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query("SELECT id FROM users")
@@ -73,6 +75,57 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_exposes_the_columns_of_rows() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let row = sqlx::query("SELECT 1 AS id, 'alice' AS name")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let columns = row.columns();
+
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns[0].name(), "id");
+    assert_eq!(columns[1].name(), "name");
+    assert_eq!(columns[1].ordinal(), 1);
+
+    assert_eq!(row.column("name").ordinal(), 1);
+    assert!(row.try_column("missing").is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_the_last_insert_id() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE users (id INTEGER AUTO_INCREMENT PRIMARY KEY, name TEXT);",
+    )
+    .await?;
+
+    for index in 1..=3_u64 {
+        let done = sqlx::query("INSERT INTO users (name) VALUES (?)")
+            .bind("alice")
+            .execute(&mut conn)
+            .await?;
+
+        assert_eq!(done.rows_affected(), 1);
+        assert_eq!(done.last_insert_id(), index);
+    }
+
+    let done = sqlx::query("UPDATE users SET name = ? WHERE id > 1")
+        .bind("bob")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(done.rows_affected(), 2);
+    assert_eq!(done.last_insert_id(), 0);
+
+    Ok(())
+}
 #[sqlx_macros::test]
 async fn it_executes_with_pool() -> anyhow::Result<()> {
     let pool: MySqlPool = MySqlPool::builder()
@@ -106,7 +159,7 @@ async fn it_drops_results_in_affected_rows() -> anyhow::Result<()> {
         .await?;
 
     // In MySQL, rows being returned isn't enough to flag it as an _affected_ row
-    assert_eq!(0, affected);
+    assert_eq!(0, affected.rows_affected());
 
     Ok(())
 }
@@ -199,3 +252,74 @@ async fn it_caches_statements() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_skips_the_statement_cache_when_not_persistent() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    for i in 0..3 {
+        let row = sqlx::query("SELECT ? AS val")
+            .bind(i)
+            .persistent(false)
+            .fetch_one(&mut conn)
+            .await?;
+
+        let val: u32 = row.get("val");
+
+        assert_eq!(i, val);
+    }
+
+    assert_eq!(0, conn.cached_statements_size());
+
+    // non-persistent statements can be interleaved with cached ones
+    let sum: i64 = sqlx::query_scalar("SELECT ? + ?")
+        .bind(1_i64)
+        .bind(2_i64)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(3, sum);
+    assert_eq!(1, conn.cached_statements_size());
+
+    let sum: i64 = sqlx::query_scalar("SELECT ? + ?")
+        .bind(3_i64)
+        .bind(4_i64)
+        .persistent(false)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(7, sum);
+    assert_eq!(1, conn.cached_statements_size());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let mut tx = conn
+        .begin_with(TransactionOptions {
+            isolation: Some(IsolationLevel::Serializable),
+            access_mode: Some(AccessMode::ReadOnly),
+            ..Default::default()
+        })
+        .await?;
+
+    // writes are rejected in a read-only transaction
+    assert!(tx
+        .execute("INSERT INTO tweet (text) VALUES ('read only')")
+        .await
+        .is_err());
+
+    tx.rollback().await?;
+
+    // the isolation level only applies to the transaction it was set for
+    let isolation: String = sqlx::query_scalar("SELECT @@transaction_isolation")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(isolation, "REPEATABLE-READ");
+
+    Ok(())
+}
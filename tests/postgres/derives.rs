@@ -381,3 +381,163 @@ async fn test_from_row_with_rename() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_rename_all() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    #[sqlx(rename_all = "camelCase")]
+    struct UserPost {
+        user_id: i32,
+        post_title: String,
+
+        #[sqlx(rename = "body")]
+        post_body: String,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let post: UserPost = sqlx::query_as(
+        r#"SELECT * from (VALUES (1, 'foo', 'bar')) posts("userId", "postTitle", body)"#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(1, post.user_id);
+    assert_eq!("foo", post.post_title);
+    assert_eq!("bar", post.post_body);
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_default_and_skip() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Account {
+        id: i32,
+
+        #[sqlx(default)]
+        name: Option<String>,
+
+        #[sqlx(default)]
+        score: i64,
+
+        #[sqlx(skip)]
+        cached: Vec<String>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account =
+        sqlx::query_as("SELECT * from (VALUES (1, 'Herp Derpinson')) accounts(id, name)")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(1, account.id);
+    assert_eq!(Some("Herp Derpinson".to_owned()), account.name);
+    assert_eq!(0, account.score);
+    assert!(account.cached.is_empty());
+
+    // a column that is present but fails to decode is still an error
+    let res: sqlx::Result<Account> =
+        sqlx::query_as("SELECT * from (VALUES (1, 'foo')) accounts(id, score)")
+            .fetch_one(&mut conn)
+            .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_flatten() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Address {
+        city: String,
+        country: String,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Account {
+        id: i32,
+
+        #[sqlx(flatten)]
+        address: Address,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account =
+        sqlx::query_as("SELECT * from (VALUES (1, 'Oslo', 'Norway')) accounts(id, city, country)")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(1, account.id);
+    assert_eq!("Oslo", account.address.city);
+    assert_eq!("Norway", account.address.country);
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_try_from() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Account {
+        #[sqlx(try_from = "i64")]
+        id: u64,
+
+        #[sqlx(try_from = "i32", default)]
+        age: u8,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account = sqlx::query_as("SELECT * from (VALUES (1::int8)) accounts(id)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(1, account.id);
+    assert_eq!(0, account.age);
+
+    let res: sqlx::Result<Account> =
+        sqlx::query_as("SELECT * from (VALUES (-1::int8)) accounts(id)")
+            .fetch_one(&mut conn)
+            .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_tuple_struct() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Account(i32, String, Option<String>);
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct RefAccount<'a>(i32, &'a str);
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account = sqlx::query_as(
+        "SELECT * from (VALUES (1, 'Herp Derpinson', null)) accounts(id, name, nickname)",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(1, account.0);
+    assert_eq!("Herp Derpinson", account.1);
+    assert_eq!(None, account.2);
+
+    let row = sqlx::query("SELECT 2, 'foo'").fetch_one(&mut conn).await?;
+    let account = RefAccount::from_row(&row)?;
+
+    assert_eq!(2, account.0);
+    assert_eq!("foo", account.1);
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use futures::TryStreamExt;
 use sqlx::{
-    query, sqlite::Sqlite, Connect, Connection, Executor, Row, SqliteConnection, SqlitePool,
+    query, sqlite::Sqlite, Argument, Column, Connect, Connection, Done, Executor, QueryBuilder,
+    Row, SqliteConnection, SqlitePool, TypeInfo,
 };
 use sqlx_test::new;
 
@@ -75,6 +76,39 @@ async fn it_fetches_and_inflates_row() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_exposes_the_columns_of_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);")
+        .await?;
+
+    conn.execute("INSERT INTO users (id, name) VALUES (1, 'alice');")
+        .await?;
+
+    let row = sqlx::query("SELECT id, name, 1 + 1 AS two FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let columns = row.columns();
+
+    assert_eq!(columns.len(), 3);
+    assert_eq!(columns[0].name(), "id");
+    assert_eq!(columns[1].name(), "name");
+    assert_eq!(columns[2].name(), "two");
+
+    assert_eq!(columns[1].ordinal(), 1);
+    assert_eq!(columns[1].type_info().map(|ty| ty.name()), Some("TEXT"));
+
+    // expressions have no declared type
+    assert!(columns[2].type_info().is_none());
+
+    assert_eq!(row.column("name").ordinal(), 1);
+    assert!(row.try_column("missing").is_err());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_fetches_in_loop() -> anyhow::Result<()> {
     // this is trying to check for any data races
@@ -145,7 +179,7 @@ async fn it_handles_empty_queries() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
     let affected = conn.execute("").await?;
 
-    assert_eq!(affected, 0);
+    assert_eq!(affected.rows_affected(), 0);
 
     Ok(())
 }
@@ -185,12 +219,12 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
         .await?;
 
     for index in 1..=10_i32 {
-        let cnt = sqlx::query("INSERT INTO users (id) VALUES (?)")
+        let done = sqlx::query("INSERT INTO users (id) VALUES (?)")
             .bind(index * 2)
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(done.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query_as("SELECT id FROM users")
@@ -203,6 +237,44 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_returns_the_last_insert_rowid() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT);")
+        .await?;
+
+    for index in 1..=3_i64 {
+        let done = sqlx::query("INSERT INTO users (name) VALUES (?)")
+            .bind("alice")
+            .execute(&mut conn)
+            .await?;
+
+        assert_eq!(done.rows_affected(), 1);
+        assert_eq!(done.last_insert_rowid(), index);
+    }
+
+    // the result of each statement is also available from `fetch_many`
+    let results: Vec<_> = conn
+        .fetch_many("INSERT INTO users (name) VALUES ('bob'); SELECT * FROM users;")
+        .try_filter_map(|step| async move { Ok(step.left()) })
+        .try_collect()
+        .await?;
+
+    assert_eq!(results[0].rows_affected(), 1);
+    assert_eq!(results[0].last_insert_rowid(), 4);
+
+    // statements that insert no rows do not report the ROWID of a previous insert
+    let done = conn
+        .execute("UPDATE users SET name = 'carol' WHERE id = 4")
+        .await?;
+
+    assert_eq!(done.rows_affected(), 1);
+    assert_eq!(done.last_insert_rowid(), 0);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_execute_multiple_statements() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -216,7 +288,7 @@ INSERT INTO users DEFAULT VALUES;
         )
         .await?;
 
-    assert_eq!(affected, 1);
+    assert_eq!(affected.rows_affected(), 1);
 
     for index in 2..5_i32 {
         let (id, other): (i32, i32) = sqlx::query_as(
@@ -291,3 +363,206 @@ async fn it_caches_statements() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_skips_the_statement_cache_when_not_persistent() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    for i in 0..3 {
+        let row = sqlx::query("SELECT ? AS val")
+            .bind(i)
+            .persistent(false)
+            .fetch_one(&mut conn)
+            .await?;
+
+        let val: i32 = row.get("val");
+
+        assert_eq!(i, val);
+    }
+
+    assert_eq!(0, conn.cached_statements_size());
+
+    // non-persistent statements can be interleaved with cached ones
+    let sum: i32 = sqlx::query_scalar("SELECT ? + ?")
+        .bind(1_i32)
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(3, sum);
+    assert_eq!(1, conn.cached_statements_size());
+
+    let sum: i32 = sqlx::query_scalar("SELECT ? + ?")
+        .bind(3_i32)
+        .bind(4_i32)
+        .persistent(false)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(7, sum);
+    assert_eq!(1, conn.cached_statements_size());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_builds_queries_with_bind_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);")
+        .await?;
+
+    let mut builder = QueryBuilder::<Sqlite>::new("INSERT INTO users (id, name) ");
+
+    builder.push_values(
+        vec![(1_i32, "alice"), (2, "bob"), (3, "carol")],
+        |mut row, (id, name)| {
+            row.push_bind(id).push_bind(name);
+        },
+    );
+
+    assert_eq!(
+        builder.sql(),
+        "INSERT INTO users (id, name) VALUES (?, ?), (?, ?), (?, ?)"
+    );
+
+    let done = builder.build().execute(&mut conn).await?;
+    assert_eq!(done.rows_affected(), 3);
+
+    let mut builder = QueryBuilder::<Sqlite>::new("SELECT id, name FROM users WHERE id IN (");
+    let mut separated = builder.separated(", ");
+
+    for id in &[1_i32, 3] {
+        separated.push_bind(*id);
+    }
+
+    separated.push_unseparated(")");
+    builder.push(" ORDER BY id");
+
+    let users: Vec<(i32, String)> = builder.build_query_as().fetch_all(&mut conn).await?;
+
+    assert_eq!(
+        users,
+        vec![(1, "alice".to_owned()), (3, "carol".to_owned())]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_prepares_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let stmt = conn.prepare("SELECT ?1 + 1 AS value").await?;
+
+    assert_eq!(stmt.sql(), "SELECT ?1 + 1 AS value");
+    assert_eq!(stmt.parameters().len(), 1);
+    assert_eq!(stmt.columns().len(), 1);
+    assert_eq!(stmt.columns()[0].name(), "value");
+
+    for i in 0..5_i32 {
+        let value: i32 = stmt.query_scalar().bind(i).fetch_one(&mut conn).await?;
+        assert_eq!(value, i + 1);
+    }
+
+    // invalid SQL fails on prepare rather than on first execution
+    assert!(conn.prepare("SELECT * FROM not_a_table").await.is_err());
+    assert!(conn.prepare("SELEC 1").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_tuples_and_slices_of_arguments() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let (a, b): (i32, String) = sqlx::query_as_with("SELECT ? + 1, ?", (10_i32, "foo"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a, 11);
+    assert_eq!(b, "foo");
+
+    let name = String::from("bar");
+    let arguments: &[&dyn Argument<'_, Sqlite>] = &[&20_i32, &name, &Some(1.5_f64)];
+
+    let (a, b, c): (i32, String, f64) = sqlx::query_as_with("SELECT ?, ?, ?", arguments)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a, 20);
+    assert_eq!(b, "bar");
+    assert_eq!(c, 1.5);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_classifies_database_errors() -> anyhow::Result<()> {
+    use sqlx::error::ErrorKind;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+PRAGMA foreign_keys = ON;
+CREATE TEMPORARY TABLE parents (id INTEGER PRIMARY KEY);
+CREATE TEMPORARY TABLE children (
+    id INTEGER PRIMARY KEY,
+    parent_id INTEGER REFERENCES parents (id),
+    name TEXT NOT NULL CHECK (name <> '')
+);
+INSERT INTO parents (id) VALUES (1);
+INSERT INTO children (id, parent_id, name) VALUES (1, 1, 'a');
+        "#,
+    )
+    .await?;
+
+    let queries = [
+        (
+            "INSERT INTO children (id, name) VALUES (1, 'b')",
+            ErrorKind::UniqueViolation,
+        ),
+        (
+            "INSERT INTO children (id, parent_id, name) VALUES (2, 2, 'b')",
+            ErrorKind::ForeignKeyViolation,
+        ),
+        (
+            "INSERT INTO children (id) VALUES (2)",
+            ErrorKind::NotNullViolation,
+        ),
+        (
+            "INSERT INTO children (id, name) VALUES (2, '')",
+            ErrorKind::CheckViolation,
+        ),
+        ("SELECT * FROM missing", ErrorKind::Other),
+    ];
+
+    for (query, kind) in &queries {
+        let err = conn.execute(*query).await.unwrap_err();
+        assert_eq!(err.as_database_error().unwrap().kind(), *kind, "{}", query);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_times_out_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let result = sqlx::query(
+        "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 100000000) \
+         SELECT count(*) FROM n",
+    )
+    .timeout(std::time::Duration::from_millis(100))
+    .fetch_one(&mut conn)
+    .await;
+
+    assert!(matches!(result, Err(sqlx::Error::QueryTimedOut)));
+
+    // the query was interrupted and the connection is still usable
+    let value: i32 = conn.fetch_one("SELECT 1").await?.try_get(0)?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}